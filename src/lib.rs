@@ -37,11 +37,93 @@
 //! | Method | Description |
 //! |:--- | --- |
 //! | [`UniqueVec::new()`](UniqueVec::new) | Creates a new empty [UniqueVec]. |
+//! | [`unique_vec![a, b, ...]`](unique_vec) | Creates a new [UniqueVec] from the given elements. |
+//! | `unique_vec_strict![a, b, ...]` | Creates a new [UniqueVec] from literals and rejects duplicates at compile time. Requires the `macros` feature. |
+//! | [`UniqueVec::with_capacity(capacity)`](UniqueVec::with_capacity) | Creates a new empty [UniqueVec] with the given capacity. |
+//! | [`UniqueVecBuilder::new()`](UniqueVecBuilder::new) | Creates a builder which accumulates entries with a configurable [DuplicatePolicy]. |
 //! | [`UniqueVec::from_iter(iterator)`](UniqueVec::from_iter) | Creates a new [UniqueVec] from an iterator. |
 //! | [`UniqueVec::push(item)`](UniqueVec::push) | Pushes a new entry to the back or returns it if already present. |
+//! | [`UniqueVec::insert(index, item)`](UniqueVec::insert) | Inserts a new entry at the given position or returns it if already present. |
 //! | [`UniqueVec::clear()`](UniqueVec::clear) | Clears all entries. |
 //! | [`UniqueVec::pop()`](UniqueVec::pop) | Removes and returns the last entry. |
 //! | [`UniqueVec::extend_from_iter(iterator)`](UniqueVec::extend_from_iter) | Extends elements by the given iterator. Returns duplicates in order. |
+//! | [`UniqueVec::remove(index)`](UniqueVec::remove) | Removes and returns the entry at the given position. |
+//! | [`UniqueVec::swap_remove(index)`](UniqueVec::swap_remove) | Removes and returns the entry at the given position by swapping in the last entry. |
+//! | [`UniqueVec::remove_item(&item)`](UniqueVec::remove_item) | Removes and returns the entry equal to the given one. |
+//! | [`UniqueVec::swap_remove_item(&item)`](UniqueVec::swap_remove_item) | Removes and returns the entry equal to the given one by swapping in the last entry. |
+//! | [`UniqueVec::truncate(len)`](UniqueVec::truncate) | Shortens to the given length, dropping all remaining entries. |
+//! | [`UniqueVec::retain(predicate)`](UniqueVec::retain) | Retains only entries for which the predicate returns `true`. |
+//! | [`UniqueVec::retain_mut(predicate)`](UniqueVec::retain_mut) | Retains only entries for which the predicate returns `true` while mutating them. Returns duplicates in order. |
+//! | [`UniqueVec::replace(&old, new)`](UniqueVec::replace) | Replaces an entry in place unless the new one is already present elsewhere. |
+//! | [`UniqueVec::replace_at(index, item)`](UniqueVec::replace_at) | Overwrites the entry at the given position unless the new one is already present elsewhere. |
+//! | [`UniqueVec::swap(a, b)`](UniqueVec::swap) | Swaps the entries at the given positions. |
+//! | [`UniqueVec::reverse()`](UniqueVec::reverse) | Reverses the order of all entries. |
+//! | [`UniqueVec::sort()`](UniqueVec::sort) | Sorts all entries. |
+//! | [`UniqueVec::sort_by(compare)`](UniqueVec::sort_by) | Sorts all entries with a comparator function. |
+//! | [`UniqueVec::sort_by_key(f)`](UniqueVec::sort_by_key) | Sorts all entries with a key extraction function. |
+//! | [`UniqueVec::sort_unstable()`](UniqueVec::sort_unstable) | Sorts all entries without preserving the order of equal elements. |
+//! | [`UniqueVec::sort_by_cached_key(f)`](UniqueVec::sort_by_cached_key) | Sorts all entries with a key extraction function which is evaluated only once per entry. |
+//! | [`UniqueVec::rotate_left(mid)`](UniqueVec::rotate_left) | Rotates entries such that the first `mid` ones move to the back. |
+//! | [`UniqueVec::rotate_right(k)`](UniqueVec::rotate_right) | Rotates entries such that the last `k` ones move to the front. |
+//! | [`UniqueVec::drain(range)`](UniqueVec::drain) | Removes the given range and returns its entries as an iterator. |
+//! | [`UniqueVec::extract_if(range, predicate)`](UniqueVec::extract_if) | Removes entries for which the predicate returns `true` and returns them as an iterator. |
+//! | [`UniqueVec::split_off(at)`](UniqueVec::split_off) | Splits off all entries starting from the given position into a new [UniqueVec]. |
+//! | [`UniqueVec::into_split_at(mid)`](UniqueVec::into_split_at) | Splits the [UniqueVec] into two at the given position. |
+//! | [`UniqueVec::append(&mut other)`](UniqueVec::append) | Moves all entries of another [UniqueVec] to the back. Returns duplicates in order. |
+//! | [`UniqueVec::pop_if(predicate)`](UniqueVec::pop_if) | Removes and returns the last entry if the predicate returns `true`. |
+//! | [`UniqueVec::move_item(from, to)`](UniqueVec::move_item) | Moves an entry to a new position and shifts all entries in between. |
+//! | [`UniqueVec::move_to_back(&item)`](UniqueVec::move_to_back) | Moves the entry equal to the given one to the back. |
+//! | [`UniqueVec::move_to_front(&item)`](UniqueVec::move_to_front) | Moves the entry equal to the given one to the front. |
+//! | [`UniqueVec::get_mut(index)`](UniqueVec::get_mut) | Mutable access to an entry which is removed if it collides with another one afterwards. |
+//! | [`UniqueVec::iter_mut()`](UniqueVec::iter_mut) | Mutable access to all entries which are deduplicated afterwards. |
+//! | [`UniqueVec::get_disjoint_mut([a, b, ...])`](UniqueVec::get_disjoint_mut) | Mutable access to multiple entries which are removed if they collide with other ones afterwards. |
+//! | [`UniqueVec::take_inner()`](UniqueVec::take_inner) | Clears all entries and returns them as a [Vec]. |
+//! | [`UniqueVec::splice(range, iterator)`](UniqueVec::splice) | Replaces the given range by new entries. Returns removed entries and duplicates in order. |
+//! | [`UniqueVec::modify(index, f)`](UniqueVec::modify) | Modifies an entry in place and removes it if it collides with another one afterwards. |
+//! | [`UniqueVec::map(f)`](UniqueVec::map) | Maps all entries into a new [UniqueVec]. Returns duplicates in order. |
+//! | [`UniqueVec::try_map(f)`](UniqueVec::try_map) | Maps all entries into a new [UniqueVec] with a fallible function. Returns duplicates in order. |
+//! | [`UniqueVec::push_or_get(item)`](UniqueVec::push_or_get) | Pushes a new entry to the back if not already present. Returns its position and a reference to it. |
+//! | [`UniqueVec::push_full(item)`](UniqueVec::push_full) | Pushes a new entry to the back if not already present. Returns its position and if it was inserted. |
+//! | [`UniqueVec::index_of(&item)`](UniqueVec::index_of) | Returns the position of the entry equal to the given one. |
+//! | [`UniqueVec::get_full(&item)`](UniqueVec::get_full) | Returns the position of the entry equal to the given one and a reference to it. |
+//! | [`UniqueVec::insert_sorted(item)`](UniqueVec::insert_sorted) | Inserts a new entry into a sorted [UniqueVec] at the correct position. |
+//! | [`UniqueVec::extend_from_slice(&slice)`](UniqueVec::extend_from_slice) | Extends elements by clones of the given slice. Returns clones of duplicates in order. |
+//! | [`UniqueVec::contains_all(iterator)`](UniqueVec::contains_all) | Checks if every element of the given iterator is contained. |
+//! | [`UniqueVec::contains_any(iterator)`](UniqueVec::contains_any) | Checks if any element of the given iterator is contained. |
+//! | [`UniqueVec::positions(iterator)`](UniqueVec::positions) | Returns the positions of all given elements. |
+//! | [`UniqueVec::remove_indices(&indices)`](UniqueVec::remove_indices) | Removes and returns all entries at the given positions. |
+//! | [`UniqueVec::remove_items(iterator)`](UniqueVec::remove_items) | Removes and returns all entries equal to the given ones. |
+//! | [`UniqueVec::reorder_by_permutation(&permutation)`](UniqueVec::reorder_by_permutation) | Reorders all entries by the given permutation. |
+//! | [`UniqueVec::reorder_to_match(iterator)`](UniqueVec::reorder_to_match) | Reorders all shared entries to match the order of the given elements. |
+//! | [`UniqueVec::retain_with_index(predicate)`](UniqueVec::retain_with_index) | Retains only entries for which the predicate, which also obtains their position, returns `true`. |
+//! | [`UniqueVec::common_with(iterator)`](UniqueVec::common_with) | Returns all given elements which are already contained. |
+//! | [`UniqueVec::try_from_vec(vec)`](UniqueVec::try_from_vec) | Creates a new [UniqueVec] from a vector or fails if it contains duplicates. |
+//! | [`UniqueVec::try_from_iter(iterator)`](UniqueVec::try_from_iter) | Creates a new [UniqueVec] from an iterator or fails at the first duplicate. |
+//! | [`UniqueVec::from_vec_unchecked(vec)`](UniqueVec::from_vec_unchecked) | Creates a new [UniqueVec] from a vector without checking for duplicates. |
+//! | [`UniqueVec::from_vec_debug_checked(vec)`](UniqueVec::from_vec_debug_checked) | Creates a new [UniqueVec] from a vector and checks for duplicates in debug builds only. |
+//! | [`UniqueVec::from_sorted_iter(iterator)`](UniqueVec::from_sorted_iter) | Creates a new [UniqueVec] from a sorted iterator in linear time. |
+//! | [`UniqueVec::from_sorted_vec(vec)`](UniqueVec::from_sorted_vec) | Creates a new [UniqueVec] from a sorted vector in linear time. |
+//! | [`UniqueVec::into_vec()`](UniqueVec::into_vec) | Returns the inner [Vec]. |
+//! | [`UniqueVec::into_boxed_slice()`](UniqueVec::into_boxed_slice) | Returns all entries as a boxed slice. |
+//! | [`UniqueVec::into_sorted_vec()`](UniqueVec::into_sorted_vec) | Returns all entries as a sorted [Vec]. |
+//! | [`UniqueVec::reserve(additional)`](UniqueVec::reserve) | Reserves capacity for at least `additional` more entries. |
+//! | [`UniqueVec::reserve_exact(additional)`](UniqueVec::reserve_exact) | Reserves the minimum capacity for `additional` more entries. |
+//! | [`UniqueVec::shrink_to_fit()`](UniqueVec::shrink_to_fit) | Shrinks the capacity as much as possible. |
+//! | [`UniqueVec::shrink_to(min_capacity)`](UniqueVec::shrink_to) | Shrinks the capacity with a lower bound. |
+//! | [`UniqueVec::try_reserve(additional)`](UniqueVec::try_reserve) | Tries to reserve capacity for at least `additional` more entries. |
+//! | [`UniqueVec::try_reserve_exact(additional)`](UniqueVec::try_reserve_exact) | Tries to reserve the minimum capacity for `additional` more entries. |
+//! | [`UniqueVec::leak()`](UniqueVec::leak) | Leaks the [UniqueVec] and returns a reference to its entries. |
+//! | [`UniqueVec::to_vec()`](UniqueVec::to_vec) | Clones all entries into a new [Vec]. |
+//! | [`UniqueVec::as_mut_vec()`](UniqueVec::as_mut_vec) | Unsafe mutable access to the inner [Vec]. |
+//! | [`UniqueVec::with_vec_mut(f)`](UniqueVec::with_vec_mut) | Mutable access to the inner [Vec] which is deduplicated afterwards. Returns duplicates in order. |
+//! | [`UniqueVec::into_raw_parts()`](UniqueVec::into_raw_parts) | Decomposes the [UniqueVec] into its raw parts. |
+//! | [`UniqueVec::from_raw_parts(ptr, len, cap)`](UniqueVec::from_raw_parts) | Unsafely creates a [UniqueVec] from its raw parts. |
+//! | [`UniqueVec::as_unique_slice()`](UniqueVec::as_unique_slice) | Returns a view of all entries as a [UniqueSlice]. |
+//! | [`UniqueVec::get_range(range)`](UniqueVec::get_range) | Returns a view of the given range as a [UniqueSlice]. |
+//! | [`UniqueVec::join_display(separator)`](UniqueVec::join_display) | Formats all entries and joins them with a separator. |
+//! | [`UniqueVec::display(separator)`](UniqueVec::display) | Returns a [Display](core::fmt::Display) adapter which joins all entries with a separator. |
+//! | [`UniqueVec::parse_delimited(s, delimiter)`](UniqueVec::parse_delimited) | Parses entries separated by a delimiter while dropping duplicates. |
+//! | [`UniqueVec::parse_delimited_strict(s, delimiter)`](UniqueVec::parse_delimited_strict) | Parses entries separated by a delimiter or fails at the first duplicate. |
 //!
 //! ## Implemented Traits
 //!
@@ -49,9 +131,24 @@
 //! | --- | --- | --- |
 //! | [Deref](core::ops::Deref) for [Vec] | ✅ | |
 //! | [DerefMut](core::ops::DerefMut) | ❌ | See the ["Create and Modify"](#create-and-modify) table above. |
-//! | [Extend] | ✅ |
+//! | [AsRef] for [slice] and [Vec] | ✅ | |
+//! | [Borrow](core::borrow::Borrow) for [slice] | ✅ | |
+//! | [Default] | ✅ |
+//! | [Extend] | ✅ | Also implemented for references of [Copy] types. |
+//! | [PartialEq] and [Eq] | ✅ | Compares entries in order. Also implemented for [Vec], [slice] and [array]. |
+//! | [Hash](core::hash::Hash) | ✅ | |
+//! | [PartialOrd] and [Ord] | ✅ | Lexicographic ordering of entries. |
 //! | [From] for [Vec] | ✅ |
-//! | [IntoIterator] | ✅ |
+//! | [From] for [slice] | ✅ | Only clones entries which are kept. |
+//! | [From] for [UniqueVec] into [Vec] | ✅ |
+//! | [From] for [BTreeSet](alloc::collections::BTreeSet) | ✅ | |
+//! | [From] for [HashSet](https://doc.rust-lang.org/std/collections/struct.HashSet.html) | ✅ | Requires the `std` feature. |
+//! | [From] and [PartialEq] for `IndexSet` | ✅ | Requires the `indexmap` feature. |
+//! | [From] for [array] | ✅ | Use [`UniqueVec::try_from_vec`] for a strict conversion. |
+//! | [Index](core::ops::Index) | ✅ | Indexing with ranges returns a [UniqueSlice]. |
+//! | [IntoIterator] | ✅ | Also implemented for `&UniqueVec`. Use [`UniqueVec::iter_mut`] for mutable iteration. |
+//! | [FromIterator] | ✅ | Silently drops duplicates. |
+//! | [FromStr](core::str::FromStr) | ✅ | Parses comma-separated entries. |
 //!
 //! ## [PartialEq] Warning
 //! Since the [UniqueVec] struct only requires the [PartialEq] trait, some unexpected behaviour
@@ -91,17 +188,115 @@
 //! ## Features
 //!
 //! - The [serde](https://serde.rs/) feature offers serialization support.
+//!   A [UniqueVec] is represented as a plain sequence, identical to a [Vec].
+//!   Deserialization fails if the sequence contains duplicates.
+//!   The `lossy` module silently drops duplicates instead.
+//!   Data previously written in formats which encode newtype structs explicitly (such as
+//!   [RON](https://docs.rs/ron)) must be read as a [Vec] and converted with [UniqueVec::from_iter].
+//! - The [serde_with](https://docs.rs/serde_with) feature offers the `UniqueSeq` and
+//!   `UniqueSeqLossy` adapters which apply the same checks to plain [Vec] fields.
+//! - The [rkyv](https://docs.rs/rkyv) feature offers zero-copy serialization.
+//!   Validating an `ArchivedUniqueVec` also checks that its entries are unique.
+//! - The [borsh](https://docs.rs/borsh) feature offers binary serialization which rejects
+//!   duplicates when deserializing.
+//! - The [schemars](https://docs.rs/schemars) feature offers a JSON schema with `uniqueItems` set.
+//! - The [bincode](https://docs.rs/bincode) feature offers native `Encode` and `Decode`
+//!   implementations which reject duplicates when decoding.
+//! - The [defmt](https://docs.rs/defmt) feature offers logging on embedded targets.
+//! - The [zeroize](https://docs.rs/zeroize) feature offers wiping of sensitive entries.
+//! - The [arbitrary](https://docs.rs/arbitrary) feature offers generation of unique instances
+//!   for fuzzing.
+//! - The [quickcheck](https://docs.rs/quickcheck) feature offers generation and shrinking of
+//!   unique instances for property tests.
+//! - The [proptest](https://docs.rs/proptest) feature offers the `proptest::unique_vec` strategy
+//!   and an `Arbitrary` implementation.
+//! - The `std` feature offers conversions from and to
+//!   [HashSet](https://doc.rust-lang.org/std/collections/struct.HashSet.html).
+//! - The [indexmap](https://docs.rs/indexmap) feature offers conversions from and to
+//!   `IndexSet` as well as comparisons with it.
+//! - The `macros` feature offers the `unique_vec_strict!` macro which checks literals for
+//!   duplicates at compile time.
 
 #![no_std]
 #![deny(missing_docs)]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Creates a [UniqueVec] containing the given elements while silently dropping duplicates.
+///
+/// ```
+/// # use uniquevec::{unique_vec, UniqueVec};
+/// let unique_vec = unique_vec![1, 2, 1, 3];
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+///
+/// let empty: UniqueVec<usize> = unique_vec![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! unique_vec {
+    () => {
+        $crate::UniqueVec::new()
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::UniqueVec::from([$($x),+])
+    };
+}
+
+/// Creates a [UniqueVec] from a list of literals and rejects duplicates at compile time.
+/// Requires the `macros` feature.
+///
+/// ```
+/// # use uniquevec::{unique_vec_strict, UniqueVec};
+/// let unique_vec: UniqueVec<_> = unique_vec_strict!["a", "b", "c"];
+/// assert_eq!(*unique_vec, vec!["a", "b", "c"]);
+/// ```
+///
+/// ```compile_fail
+/// # use uniquevec::unique_vec_strict;
+/// // This will not compile
+/// let unique_vec = unique_vec_strict![1, 2, 0x1];
+/// ```
+///
+/// Values which are equal at runtime are detected regardless of how they are written.
+///
+/// ```compile_fail
+/// # use uniquevec::unique_vec_strict;
+/// let unique_vec = unique_vec_strict![b'a', 97u8];
+/// ```
+///
+/// ```compile_fail
+/// # use uniquevec::unique_vec_strict;
+/// let unique_vec = unique_vec_strict![-0, 0];
+/// ```
+///
+/// Floats are compared at `f32` precision unless any of them has the `f64` suffix.
+/// In this case, the error message suggests to add the suffix.
+///
+/// ```compile_fail
+/// # use uniquevec::unique_vec_strict;
+/// let unique_vec = unique_vec_strict![1.0f32, 1.00000001f32];
+/// ```
+///
+/// ```compile_fail
+/// # use uniquevec::{unique_vec_strict, UniqueVec};
+/// let unique_vec: UniqueVec<f64> = unique_vec_strict![1.0, 1.00000001];
+/// ```
+///
+/// ```
+/// # use uniquevec::unique_vec_strict;
+/// let unique_vec = unique_vec_strict![1.0f64, 1.00000001];
+/// assert_eq!(unique_vec.len(), 2);
+/// ```
+#[cfg(feature = "macros")]
+pub use uniquevec_macros::unique_vec_strict;
+
 /// A unique vector containing only non-recurring values but in the correct order.
 ///
 /// ```
@@ -112,14 +307,86 @@ use serde::{Deserialize, Serialize};
 /// let res = unique_vec.push(1_usize);
 /// assert!(res.is_some());
 /// assert_eq!(*unique_vec, vec![1, 2]);
+///
+/// // Two UniqueVecs are equal if they contain equal entries in the same order
+/// assert_eq!(unique_vec, UniqueVec::from_iter([1, 2]).0);
+/// assert_ne!(unique_vec, UniqueVec::from_iter([2, 1]).0);
+///
+/// // It can also be used as a key in maps
+/// let mut map = std::collections::HashMap::new();
+/// map.insert(unique_vec.clone(), "first");
+/// assert_eq!(map.get(&unique_vec), Some(&"first"));
+///
+/// // They can also be compared with vectors, slices and arrays
+/// assert_eq!(unique_vec, vec![1, 2]);
+/// assert_eq!(unique_vec, [1, 2]);
+/// assert_eq!(&[1, 2][..], unique_vec);
+///
+/// // Ordering is lexicographic
+/// assert!(unique_vec < UniqueVec::from_iter([1, 3]).0);
+/// assert!(unique_vec > UniqueVec::from_iter([1]).0);
 /// ```
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UniqueVec<T>(Vec<T>);
 
 /// Identical to the [UniqueVec] struct but only supports entry types which implement [Eq].
+///
+/// With the `serde` feature it is (de)serialized exactly like a [UniqueVec].
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(transparent, bound(deserialize = "T: Deserialize<'de> + Eq"))
+)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UniqueVecEq<T>(UniqueVec<T>);
 
+impl<T> UniqueVecEq<T>
+where
+    T: Eq,
+{
+    /// Creates an new empty [UniqueVecEq].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVecEq;
+    /// let mut unique_vec = UniqueVecEq::new();
+    /// unique_vec.push(1_usize);
+    /// assert_eq!(**unique_vec, vec![1]);
+    /// ```
+    pub const fn new() -> Self {
+        Self(UniqueVec::new())
+    }
+}
+
+/// ```
+/// # use uniquevec::*;
+/// #[derive(Default)]
+/// struct Registry {
+///     ids: UniqueVec<u32>,
+///     names: UniqueVecEq<&'static str>,
+/// }
+/// let mut registry = Registry::default();
+/// registry.ids.push(1);
+/// let ids = core::mem::take(&mut registry.ids);
+/// assert_eq!(*ids, vec![1]);
+/// assert!(registry.ids.is_empty());
+/// assert!(registry.names.is_empty());
+/// ```
+impl<T> Default for UniqueVecEq<T>
+where
+    T: Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> core::ops::Deref for UniqueVecEq<T>
 where
     T: Eq,
@@ -167,12 +434,109 @@ where
     }
 }
 
+impl<T, const N: usize> From<[T; N]> for UniqueVecEq<T>
+where
+    T: Eq,
+{
+    fn from(value: [T; N]) -> Self {
+        Self(UniqueVec::from(value))
+    }
+}
+
+/// Error which is returned when an element could not be added since an equal element is
+/// already present.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+/// let err = unique_vec.replace_at(0, 3).unwrap_err();
+/// assert_eq!(err.index, 2);
+/// assert_eq!(err.value, 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateError<T> {
+    /// Position of the duplicate entry.
+    /// See the method which returned this error for its exact meaning.
+    pub index: usize,
+    /// The rejected value.
+    pub value: T,
+}
+
+impl<T> core::fmt::Display for DuplicateError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "duplicate entry at index {}", self.index)
+    }
+}
+
+impl<T> core::error::Error for DuplicateError<T> where T: core::fmt::Debug {}
+
+/// Error which is returned when parsing a [UniqueVec] from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError<T, E> {
+    /// An entry could not be parsed.
+    Invalid {
+        /// Position of the entry in the input.
+        index: usize,
+        /// The error returned by the parser of the entry.
+        error: E,
+    },
+    /// An entry is a duplicate of a previous one.
+    /// The index is the position of the entry in the input.
+    Duplicate(DuplicateError<T>),
+}
+
+impl<T, E> core::fmt::Display for ParseError<T, E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Invalid { index, error } => write!(f, "invalid entry at index {index}: {error}"),
+            Self::Duplicate(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<T, E> core::error::Error for ParseError<T, E>
+where
+    T: core::fmt::Debug,
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            Self::Duplicate(_) => None,
+        }
+    }
+}
+
 impl<T> UniqueVec<T> {
     /// Creates an new empty [UniqueVec].
-    pub fn new() -> Self {
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// use std::sync::Mutex;
+    /// static CACHE: Mutex<UniqueVec<u32>> = Mutex::new(UniqueVec::new());
+    /// CACHE.lock().unwrap().push(1);
+    /// assert_eq!(**CACHE.lock().unwrap(), vec![1]);
+    /// ```
+    pub const fn new() -> Self {
         Self(Vec::new())
     }
 
+    /// Creates a new empty [UniqueVec] with at least the specified capacity.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::with_capacity(10);
+    /// assert!(unique_vec.capacity() >= 10);
+    /// unique_vec.push(1);
+    /// assert_eq!(*unique_vec, vec![1]);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
     /// Construct a new [UniqueVec] from a given vector.
     /// This function will also return the rest which was not inserted into the [UniqueVec].
     ///
@@ -183,6 +547,7 @@ impl<T> UniqueVec<T> {
     /// assert_eq!(*unique_vec, vec![1, 33, 2, 0, 4, 56]);
     /// assert_eq!(rest, vec![33, 2]);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn from_iter(iter: impl IntoIterator<Item = T>) -> (Self, Vec<T>)
     where
         T: PartialEq,
@@ -224,6 +589,44 @@ impl<T> UniqueVec<T> {
         }
     }
 
+    /// Inserts an element at position `index` if not already present.
+    /// All elements after it are shifted to the right.
+    /// Similarly to [UniqueVec::push], the element is returned if it is already contained.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert!(unique_vec.insert(1, 7).is_none());
+    /// assert_eq!(unique_vec.insert(0, 3), Some(3));
+    /// assert_eq!(*unique_vec, vec![1, 7, 2, 3]);
+    /// ```
+    ///
+    /// An out-of-bounds index panics even if the element is a duplicate.
+    /// ```should_panic
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// unique_vec.insert(10, 1);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let len = self.0.len();
+        assert!(
+            index <= len,
+            "index (is {index}) should be <= len (is {len})"
+        );
+        if self.0.contains(&element) {
+            Some(element)
+        } else {
+            self.0.insert(index, element);
+            None
+        }
+    }
+
     /// Empties the [UniqueVec] returning all values
     pub fn clear(&mut self) {
         self.0.clear()
@@ -237,52 +640,3432 @@ impl<T> UniqueVec<T> {
     /// ```
     /// # use uniquevec::UniqueVec;
     /// let mut unique_vec = UniqueVec::from_iter(vec![3, 1, 19]).0;
-    /// let other = unique_vec.extend_from_iter([73, 1843, 19, 3]);
-    /// assert_eq!(other, vec![19, 3]);
+    /// let other = unique_vec.extend_from_iter([73, 1843, 19, 3, 73]);
+    /// assert_eq!(other, vec![19, 3, 73]);
+    /// assert_eq!(*unique_vec, vec![3, 1, 19, 73, 1843]);
     /// ```
     pub fn extend_from_iter<S: IntoIterator<Item = T>>(&mut self, iter: S) -> Vec<T>
     where
         T: PartialEq,
     {
-        let iter = iter.into_iter();
-        let (duplicates, new_entries) =
-            core::iter::Iterator::partition::<Vec<T>, _>(iter, |elem| self.contains(elem));
-        self.0.extend(new_entries);
-        duplicates
+        iter.into_iter()
+            .filter_map(|element| self.push(element))
+            .collect()
     }
-}
 
-impl<T> core::iter::Extend<T> for UniqueVec<T>
-where
-    T: PartialEq,
-{
-    fn extend<S: IntoIterator<Item = T>>(&mut self, iter: S) {
-        self.extend_from_iter(iter);
+    /// Removes and returns the element at position `index`.
+    /// All elements after it are shifted to the left.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.remove(1), 2);
+    /// assert_eq!(*unique_vec, vec![1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
     }
-}
 
-impl<T> core::ops::Deref for UniqueVec<T> {
-    type Target = Vec<T>;
+    /// Removes and returns the element at position `index`.
+    /// The removed element is replaced by the last element of the [UniqueVec].
+    /// This does not preserve ordering but is `O(1)`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// assert_eq!(unique_vec.swap_remove(1), 2);
+    /// assert_eq!(*unique_vec, vec![1, 4, 3]);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.0.swap_remove(index)
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Removes the entry which is equal to `element` and returns it.
+    /// All elements after it are shifted to the left.
+    /// Returns [None] if no such entry is present.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b", "c"]).0;
+    /// assert_eq!(unique_vec.remove_item(&"b"), Some("b"));
+    /// assert_eq!(unique_vec.remove_item(&"d"), None);
+    /// assert_eq!(*unique_vec, vec!["a", "c"]);
+    /// ```
+    pub fn remove_item(&mut self, element: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let index = self.index_of(element)?;
+        Some(self.0.remove(index))
     }
-}
 
-impl<T> From<Vec<T>> for UniqueVec<T>
-where
-    T: PartialEq,
-{
-    fn from(value: Vec<T>) -> Self {
-        Self::from_iter(value).0
+    /// Removes the entry which is equal to `element` and returns it.
+    /// The removed element is replaced by the last element of the [UniqueVec].
+    /// Returns [None] if no such entry is present.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b", "c", "d"]).0;
+    /// assert_eq!(unique_vec.swap_remove_item(&"b"), Some("b"));
+    /// assert_eq!(unique_vec.swap_remove_item(&"e"), None);
+    /// assert_eq!(*unique_vec, vec!["a", "d", "c"]);
+    /// ```
+    pub fn swap_remove_item(&mut self, element: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let index = self.index_of(element)?;
+        Some(self.0.swap_remove(index))
     }
-}
 
-impl<T> IntoIterator for UniqueVec<T> {
-    type Item = T;
-    type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
+    /// Shortens the [UniqueVec] to the first `len` elements and drops the rest.
+    /// Has no effect if `len` is greater or equal to the current length.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// unique_vec.truncate(2);
+    /// assert_eq!(*unique_vec, vec![1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+    /// Retains only the elements for which the predicate returns `true`.
+    /// The order of the remaining elements is preserved.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// unique_vec.retain(|x| x % 2 == 1);
+    /// assert_eq!(*unique_vec, vec![1, 3, 5]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.0.retain(f)
     }
-}
+
+    /// Retains only the elements for which the predicate returns `true` while allowing to
+    /// mutate them.
+    /// Since mutation may produce duplicates, the [UniqueVec] is checked again afterwards.
+    /// Only the first occurrence of every entry is kept and all other ones are returned in
+    /// order.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5, 6]).0;
+    /// let rest = unique_vec.retain_mut(|x| {
+    ///     *x /= 2;
+    ///     *x != 0
+    /// });
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// assert_eq!(rest, vec![1, 2]);
+    ///
+    /// // The entries are also deduplicated if the predicate panics
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     unique_vec.retain_mut(|x| {
+    ///         assert!(*x < 3);
+    ///         *x = 7;
+    ///         true
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(*unique_vec, vec![7, 3]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F) -> Vec<T>
+    where
+        F: FnMut(&mut T) -> bool,
+        T: PartialEq,
+    {
+        let mut guard = self.iter_mut();
+        guard.vec.retain_mut(f);
+        guard.finish()
+    }
+
+    /// Replaces the entry equal to `old` by `new` while keeping its position.
+    /// On success, the previous entry is returned.
+    /// If `old` is not present or `new` is already contained at a different position,
+    /// nothing is changed and `new` is given back.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b", "c"]).0;
+    /// assert_eq!(unique_vec.replace(&"b", "x"), Ok("b"));
+    /// assert_eq!(unique_vec.replace(&"a", "c"), Err("c"));
+    /// assert_eq!(unique_vec.replace(&"d", "y"), Err("y"));
+    /// assert_eq!(*unique_vec, vec!["a", "x", "c"]);
+    /// ```
+    pub fn replace(&mut self, old: &T, new: T) -> Result<T, T>
+    where
+        T: PartialEq,
+    {
+        let Some(index) = self.index_of(old) else {
+            return Err(new);
+        };
+        if self
+            .0
+            .iter()
+            .enumerate()
+            .any(|(n, x)| n != index && x == &new)
+        {
+            return Err(new);
+        }
+        Ok(core::mem::replace(&mut self.0[index], new))
+    }
+
+    /// Overwrites the entry at position `index` with `value` and returns the previous entry.
+    /// If `value` is already present at a different position, nothing is changed and a
+    /// [DuplicateError] containing this position and the rejected value is returned.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.replace_at(1, 5), Ok(2));
+    /// assert_eq!(unique_vec.replace_at(1, 5), Ok(5));
+    /// assert_eq!(
+    ///     unique_vec.replace_at(0, 3),
+    ///     Err(DuplicateError { index: 2, value: 3 })
+    /// );
+    /// assert_eq!(*unique_vec, vec![1, 5, 3]);
+    /// ```
+    pub fn replace_at(&mut self, index: usize, value: T) -> Result<T, DuplicateError<T>>
+    where
+        T: PartialEq,
+    {
+        let len = self.0.len();
+        assert!(index < len, "index (is {index}) should be < len (is {len})");
+        if let Some(n) = self
+            .0
+            .iter()
+            .enumerate()
+            .position(|(n, x)| n != index && x == &value)
+        {
+            return Err(DuplicateError { index: n, value });
+        }
+        Ok(core::mem::replace(&mut self.0[index], value))
+    }
+
+    /// Swaps the two entries at positions `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// unique_vec.swap(0, 2);
+    /// assert_eq!(*unique_vec, vec![3, 2, 1]);
+    ///
+    /// let mut unique_vec_eq: UniqueVecEq<_> = unique_vec.into();
+    /// unique_vec_eq.swap(0, 1);
+    /// assert_eq!(**unique_vec_eq, vec![2, 3, 1]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b)
+    }
+
+    /// Reverses the order of all entries in place.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// unique_vec.reverse();
+    /// assert_eq!(*unique_vec, vec![3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.0.reverse()
+    }
+
+    /// Sorts all entries while preserving the initial order of equal elements.
+    /// See [slice::sort].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([3, 1, 2]).0;
+    /// unique_vec.sort();
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.0.sort()
+    }
+
+    /// Sorts all entries with the given comparator function.
+    /// See [slice::sort_by].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([3, 1, 2]).0;
+    /// unique_vec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(*unique_vec, vec![3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.0.sort_by(compare)
+    }
+
+    /// Sorts all entries with the given key extraction function.
+    /// See [slice::sort_by_key].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([-3, 1, -2]).0;
+    /// unique_vec.sort_by_key(|x| x * x);
+    /// assert_eq!(*unique_vec, vec![1, -2, -3]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.0.sort_by_key(f)
+    }
+
+    /// Sorts all entries without preserving the initial order of equal elements.
+    /// See [slice::sort_unstable].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([3, 1, 2]).0;
+    /// unique_vec.sort_unstable();
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.0.sort_unstable()
+    }
+
+    /// Sorts all entries with the given key extraction function which is called only once per
+    /// element.
+    /// See [slice::sort_by_cached_key].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["10", "9", "100"]).0;
+    /// unique_vec.sort_by_cached_key(|x| x.parse::<usize>().unwrap());
+    /// assert_eq!(*unique_vec, vec!["9", "10", "100"]);
+    /// ```
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.0.sort_by_cached_key(f)
+    }
+
+    /// Rotates all entries in place such that the first `mid` elements move to the end.
+    /// See [slice::rotate_left].
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// unique_vec.rotate_left(1);
+    /// assert_eq!(*unique_vec, vec![2, 3, 4, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.0.rotate_left(mid)
+    }
+
+    /// Rotates all entries in place such that the last `k` elements move to the front.
+    /// See [slice::rotate_right].
+    ///
+    /// # Panics
+    /// Panics if `k > len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// unique_vec.rotate_right(1);
+    /// assert_eq!(*unique_vec, vec![4, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        self.0.rotate_right(k)
+    }
+
+    /// Removes the entries in the given range and returns them as an iterator.
+    /// See [Vec::drain].
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// let drained: Vec<_> = unique_vec.drain(1..3).collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(*unique_vec, vec![1, 4, 5]);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> alloc::vec::Drain<'_, T>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.0.drain(range)
+    }
+
+    /// Removes all entries in the given range for which the predicate returns `true` and
+    /// returns them as an iterator.
+    /// Contrary to [Vec::extract_if], the predicate only obtains a shared reference since
+    /// mutating the remaining entries could introduce duplicates.
+    /// If the returned iterator is dropped before being fully consumed, the remaining entries
+    /// are kept.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5, 6]).0;
+    /// let even: Vec<_> = unique_vec.extract_if(.., |x| x % 2 == 0).collect();
+    /// assert_eq!(even, vec![2, 4, 6]);
+    /// assert_eq!(*unique_vec, vec![1, 3, 5]);
+    /// ```
+    pub fn extract_if<'a, F, R>(
+        &'a mut self,
+        range: R,
+        mut filter: F,
+    ) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.0.extract_if(range, move |x| filter(x))
+    }
+
+    /// Splits the [UniqueVec] into two at the given index.
+    /// Returns a new [UniqueVec] containing the entries `[at, len)` while `self` retains the
+    /// entries `[0, at)`.
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// let tail = unique_vec.split_off(1);
+    /// assert_eq!(*unique_vec, vec![1]);
+    /// assert_eq!(*tail, vec![2, 3, 4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self(self.0.split_off(at))
+    }
+
+    /// Consumes the [UniqueVec] and splits it into two at the given index.
+    /// The first one contains the entries `[0, mid)` and the second one `[mid, len)`.
+    /// To obtain borrowed halves which keep the uniqueness guarantee, index the [UniqueVec] with a
+    /// range and use [UniqueSlice::split_at], e.g. `unique_vec[..].split_at(mid)`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// let (left, right) = unique_vec[..].split_at(1);
+    /// assert_eq!(**left, [1]);
+    /// assert_eq!(**right, [2, 3, 4]);
+    ///
+    /// let (left, right) = unique_vec.into_split_at(3);
+    /// assert_eq!(*left, vec![1, 2, 3]);
+    /// assert_eq!(*right, vec![4]);
+    /// ```
+    pub fn into_split_at(mut self, mid: usize) -> (Self, Self) {
+        let right = self.split_off(mid);
+        (self, right)
+    }
+
+    /// Moves all entries of `other` to the back of `self`, leaving `other` empty.
+    /// Entries which are already contained in `self` are not added but returned in order.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let mut other = UniqueVec::from_iter([4, 2, 5, 1]).0;
+    /// let duplicates = unique_vec.append(&mut other);
+    /// assert_eq!(*unique_vec, vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(duplicates, vec![2, 1]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) -> Vec<T>
+    where
+        T: PartialEq,
+    {
+        // Since other is unique itself, we only need to compare against the initial entries.
+        let len = self.0.len();
+        self.0.reserve(other.0.len());
+        let mut duplicates = Vec::new();
+        for element in other.0.drain(..) {
+            if self.0[..len].contains(&element) {
+                duplicates.push(element);
+            } else {
+                self.0.push(element);
+            }
+        }
+        duplicates
+    }
+
+    /// Removes and returns the last entry if the predicate returns `true`.
+    /// Contrary to [Vec::pop_if], the predicate only obtains a shared reference such that the
+    /// entry cannot be modified into a duplicate.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.pop_if(|x| *x == 2), None);
+    /// assert_eq!(unique_vec.pop_if(|x| *x == 3), Some(3));
+    /// assert_eq!(*unique_vec, vec![1, 2]);
+    /// ```
+    pub fn pop_if<F>(&mut self, predicate: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        self.0.pop_if(|x| predicate(x))
+    }
+
+    /// Moves the entry at position `from` to position `to` and shifts all entries in between.
+    /// See also `IndexMap::move_index` of the [indexmap](https://docs.rs/indexmap) crate.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` are out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// unique_vec.move_item(1, 3);
+    /// assert_eq!(*unique_vec, vec![1, 3, 4, 2, 5]);
+    /// unique_vec.move_item(4, 0);
+    /// assert_eq!(*unique_vec, vec![5, 1, 3, 4, 2]);
+    /// ```
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        let len = self.0.len();
+        assert!(from < len, "from (is {from}) should be < len (is {len})");
+        assert!(to < len, "to (is {to}) should be < len (is {len})");
+        if from < to {
+            self.0[from..=to].rotate_left(1);
+        } else {
+            self.0[to..=from].rotate_right(1);
+        }
+    }
+
+    /// Moves the entry equal to `element` to the back and shifts all entries after it to the
+    /// left.
+    /// Returns `false` if no such entry is present.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert!(unique_vec.move_to_back(&1));
+    /// assert!(!unique_vec.move_to_back(&4));
+    /// assert_eq!(*unique_vec, vec![2, 3, 1]);
+    /// ```
+    pub fn move_to_back(&mut self, element: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.index_of(element) {
+            Some(index) => {
+                self.0[index..].rotate_left(1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the entry equal to `element` to the front and shifts all entries before it to the
+    /// right.
+    /// Returns `false` if no such entry is present.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert!(unique_vec.move_to_front(&3));
+    /// assert!(!unique_vec.move_to_front(&4));
+    /// assert_eq!(*unique_vec, vec![3, 1, 2]);
+    /// ```
+    pub fn move_to_front(&mut self, element: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.index_of(element) {
+            Some(index) => {
+                self.0[..=index].rotate_right(1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a guard which gives mutable access to the entry at position `index` or [None]
+    /// if `index` is out of bounds.
+    /// After modification, the guard checks that the entry does not collide with any other
+    /// entry when it is dropped.
+    /// In this case, the modified entry is removed.
+    /// Use [EntryMut::finish] to obtain the removed entry.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// *unique_vec.get_mut(0).unwrap() = 10;
+    /// assert_eq!(*unique_vec, vec![10, 2, 3]);
+    ///
+    /// // This entry now collides with another one and is thus removed
+    /// let mut entry = unique_vec.get_mut(1).unwrap();
+    /// *entry = 3;
+    /// assert_eq!(entry.finish(), Some(3));
+    /// assert_eq!(*unique_vec, vec![10, 3]);
+    ///
+    /// // Leaking the guard leaves an empty UniqueVec behind
+    /// let mut entry = unique_vec.get_mut(0).unwrap();
+    /// *entry = 3;
+    /// core::mem::forget(entry);
+    /// assert!(unique_vec.is_empty());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<EntryMut<'_, T>>
+    where
+        T: PartialEq,
+    {
+        if index < self.0.len() {
+            let vec = core::mem::take(&mut self.0);
+            Some(EntryMut {
+                unique_vec: self,
+                vec,
+                index,
+                finished: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a guard which gives mutable access to all entries.
+    /// When the guard is dropped, the [UniqueVec] is deduplicated again such that only the
+    /// first occurrence of every entry is kept.
+    /// Use [IterMutGuard::finish] to obtain the removed entries.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "B", "A", "c"].map(String::from)).0;
+    /// let mut guard = unique_vec.iter_mut();
+    /// for entry in guard.iter_mut() {
+    ///     *entry = entry.to_lowercase();
+    /// }
+    /// assert_eq!(guard.finish(), vec!["a"]);
+    /// assert_eq!(*unique_vec, vec!["a", "b", "c"]);
+    ///
+    /// // Leaking the guard leaves an empty UniqueVec behind
+    /// let mut guard = unique_vec.iter_mut();
+    /// for entry in guard.iter_mut() {
+    ///     entry.clear();
+    /// }
+    /// core::mem::forget(guard);
+    /// assert!(unique_vec.is_empty());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMutGuard<'_, T>
+    where
+        T: PartialEq,
+    {
+        let vec = core::mem::take(&mut self.0);
+        IterMutGuard {
+            unique_vec: self,
+            vec,
+            finished: false,
+        }
+    }
+
+    /// Returns a guard which gives mutable access to multiple distinct entries at once.
+    /// See [slice::get_disjoint_mut].
+    /// When the guard is dropped, every modified entry which collides with any other entry is
+    /// removed.
+    /// Among multiple modified entries which are equal to each other, the first one is kept.
+    /// Use [DisjointMutGuard::finish] to obtain the removed entries.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([(1, 'a'), (2, 'b'), (3, 'c')]).0;
+    /// let mut guard = unique_vec.get_disjoint_mut([0, 2]).unwrap();
+    /// let [first, last] = guard.entries();
+    /// core::mem::swap(&mut first.1, &mut last.1);
+    /// assert!(guard.finish().is_empty());
+    /// assert_eq!(*unique_vec, vec![(1, 'c'), (2, 'b'), (3, 'a')]);
+    ///
+    /// // Both modified entries collide with the first one
+    /// let mut guard = unique_vec.get_disjoint_mut([2, 1]).unwrap();
+    /// let [a, b] = guard.entries();
+    /// *a = (1, 'c');
+    /// *b = (1, 'c');
+    /// assert_eq!(guard.finish(), vec![(1, 'c'), (1, 'c')]);
+    /// assert_eq!(*unique_vec, vec![(1, 'c')]);
+    ///
+    /// assert!(unique_vec.get_disjoint_mut([1, 1]).is_err());
+    ///
+    /// // Leaking the guard leaves an empty UniqueVec behind
+    /// let guard = unique_vec.get_disjoint_mut([0]).unwrap();
+    /// core::mem::forget(guard);
+    /// assert!(unique_vec.is_empty());
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<DisjointMutGuard<'_, T, N>, core::slice::GetDisjointMutError>
+    where
+        T: PartialEq,
+    {
+        self.0.get_disjoint_mut(indices)?;
+        let vec = core::mem::take(&mut self.0);
+        Ok(DisjointMutGuard {
+            unique_vec: self,
+            vec,
+            indices,
+            finished: false,
+        })
+    }
+
+    /// Empties the [UniqueVec] and returns all entries together with their allocation.
+    /// To obtain the entries as an iterator, use [UniqueVec::drain] with the full range `..`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let inner = unique_vec.take_inner();
+    /// assert_eq!(inner, vec![1, 2, 3]);
+    /// assert!(unique_vec.is_empty());
+    /// ```
+    pub fn take_inner(&mut self) -> Vec<T> {
+        core::mem::take(&mut self.0)
+    }
+
+    /// Replaces the entries in the given range by the elements of `replace_with`.
+    /// See [Vec::splice].
+    /// Elements which are already contained outside of the range or which occur multiple times
+    /// in `replace_with` are skipped.
+    /// Returns the removed entries and the skipped elements, both in order.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+    /// let (removed, duplicates) = unique_vec.splice(1..3, [3, 7, 1, 2, 7]);
+    /// assert_eq!(removed, vec![2, 3]);
+    /// assert_eq!(duplicates, vec![1, 7]);
+    /// assert_eq!(*unique_vec, vec![1, 3, 7, 2, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> (Vec<T>, Vec<T>)
+    where
+        R: core::ops::RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        T: PartialEq,
+    {
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(&n) => n,
+            core::ops::Bound::Excluded(&n) => n + 1,
+            core::ops::Bound::Unbounded => 0,
+        };
+        let removed: Vec<T> = self.0.drain(range).collect();
+        let mut new_entries = Vec::new();
+        let mut duplicates = Vec::new();
+        for element in replace_with {
+            if self.0.contains(&element) || new_entries.contains(&element) {
+                duplicates.push(element);
+            } else {
+                new_entries.push(element);
+            }
+        }
+        self.0.splice(start..start, new_entries);
+        (removed, duplicates)
+    }
+
+    /// Modifies the entry at position `index` with the given closure and returns its result.
+    /// If the modified entry is equal to any other entry afterwards, it is removed and returned
+    /// as a [DuplicateError] together with the current position of the other entry.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.modify(0, |x| *x += 10), Ok(()));
+    /// assert_eq!(
+    ///     unique_vec.modify(0, |x| *x = 3),
+    ///     Err(DuplicateError { index: 1, value: 3 })
+    /// );
+    /// assert_eq!(*unique_vec, vec![2, 3]);
+    ///
+    /// // The entry is also checked if the closure panics
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     unique_vec.modify(1, |x| {
+    ///         *x = 2;
+    ///         panic!();
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(*unique_vec, vec![2]);
+    /// ```
+    pub fn modify<F, R>(&mut self, index: usize, f: F) -> Result<R, DuplicateError<T>>
+    where
+        F: FnOnce(&mut T) -> R,
+        T: PartialEq,
+    {
+        let len = self.0.len();
+        assert!(index < len, "index (is {index}) should be < len (is {len})");
+        // The guard checks for collisions even if the closure panics
+        let mut entry = EntryMut {
+            vec: core::mem::take(&mut self.0),
+            unique_vec: self,
+            index,
+            finished: false,
+        };
+        let result = f(&mut entry);
+        match entry.validate() {
+            Some((index, value)) => Err(DuplicateError { index, value }),
+            None => Ok(result),
+        }
+    }
+
+    /// Consumes the [UniqueVec] and applies the given function to every entry.
+    /// Since previously distinct entries may be mapped onto equal values, this function also
+    /// returns all mapped values which were dropped as duplicates in order.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["a", "B", "b", "A"]).0;
+    /// let (mapped, rest) = unique_vec.map(|x| x.to_lowercase());
+    /// assert_eq!(*mapped, vec!["a", "b"]);
+    /// assert_eq!(rest, vec!["b", "a"]);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> (UniqueVec<U>, Vec<U>)
+    where
+        F: FnMut(T) -> U,
+        U: PartialEq,
+    {
+        UniqueVec::from_iter(self.0.into_iter().map(f))
+    }
+
+    /// Similar to [UniqueVec::map] but the given function may fail.
+    /// Returns the first error which occurred.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["1", "01", "2"]).0;
+    /// let (mapped, rest) = unique_vec.clone().try_map(|x| x.parse::<u8>()).unwrap();
+    /// assert_eq!(*mapped, vec![1, 2]);
+    /// assert_eq!(rest, vec![1]);
+    ///
+    /// let unique_vec = UniqueVec::from_iter(["1", "x", "2"]).0;
+    /// assert!(unique_vec.try_map(|x| x.parse::<u8>()).is_err());
+    /// ```
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<(UniqueVec<U>, Vec<U>), E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+        U: PartialEq,
+    {
+        let mut new_inner = Vec::with_capacity(self.0.len());
+        let mut rest = Vec::new();
+        for element in self.0 {
+            let element = f(element)?;
+            if new_inner.contains(&element) {
+                rest.push(element);
+            } else {
+                new_inner.push(element);
+            }
+        }
+        Ok((UniqueVec(new_inner), rest))
+    }
+
+    /// Adds an element to the back if not already present.
+    /// Returns the position of the entry equal to `element` together with a reference to it.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b"]).0;
+    /// assert_eq!(unique_vec.push_or_get("c"), (2, &"c"));
+    /// assert_eq!(unique_vec.push_or_get("a"), (0, &"a"));
+    /// assert_eq!(*unique_vec, vec!["a", "b", "c"]);
+    /// ```
+    pub fn push_or_get(&mut self, element: T) -> (usize, &T)
+    where
+        T: PartialEq,
+    {
+        let index = match self.index_of(&element) {
+            Some(index) => index,
+            None => {
+                self.0.push(element);
+                self.0.len() - 1
+            }
+        };
+        (index, &self.0[index])
+    }
+
+    /// Adds an element to the back if not already present.
+    /// Returns the position of the entry equal to `element` and whether it was newly inserted.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b"]).0;
+    /// assert_eq!(unique_vec.push_full("c"), (2, true));
+    /// assert_eq!(unique_vec.push_full("a"), (0, false));
+    /// assert_eq!(*unique_vec, vec!["a", "b", "c"]);
+    /// ```
+    pub fn push_full(&mut self, element: T) -> (usize, bool)
+    where
+        T: PartialEq,
+    {
+        match self.index_of(&element) {
+            Some(index) => (index, false),
+            None => {
+                self.0.push(element);
+                (self.0.len() - 1, true)
+            }
+        }
+    }
+
+    /// Returns the position of the entry equal to `element` or [None] if no such entry is
+    /// present.
+    /// The entry type may be compared by any borrowed form.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["a", "b", "c"].map(String::from)).0;
+    /// assert_eq!(unique_vec.index_of("b"), Some(1));
+    /// assert_eq!(unique_vec.index_of("d"), None);
+    /// ```
+    pub fn index_of<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.0.iter().position(|x| x.borrow() == element)
+    }
+
+    /// Returns the position of the entry equal to `element` together with a reference to it.
+    /// The entry type may be compared by any borrowed form.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["a", "b", "c"].map(String::from)).0;
+    /// assert_eq!(unique_vec.get_full("c"), Some((2, &String::from("c"))));
+    /// assert_eq!(unique_vec.get_full("d"), None);
+    /// ```
+    pub fn get_full<Q>(&self, element: &Q) -> Option<(usize, &T)>
+    where
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let index = self.index_of(element)?;
+        Some((index, &self.0[index]))
+    }
+
+    /// Inserts an element into a sorted [UniqueVec] such that it remains sorted.
+    /// Returns `Ok(index)` with the position of the newly inserted entry or `Err(index)` with
+    /// the position of an already present equal entry.
+    /// See also [slice::binary_search].
+    ///
+    /// If the [UniqueVec] is not sorted, the insertion position is unspecified.
+    /// The entries are guaranteed to stay unique nevertheless.
+    ///
+    /// Already present entries are found by binary search with `O(log n)` comparisons.
+    /// Since binary search can miss equal entries of an unsorted [UniqueVec], every successful
+    /// insertion additionally compares the element against all entries in `O(n)`.
+    /// This does not change the overall cost since shifting the entries during insertion is
+    /// `O(n)` as well.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 3, 5]).0;
+    /// assert_eq!(unique_vec.insert_sorted(4), Ok(2));
+    /// assert_eq!(unique_vec.insert_sorted(3), Err(1));
+    /// assert_eq!(*unique_vec, vec![1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, element: T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        match self.0.binary_search(&element) {
+            Ok(index) => Err(index),
+            // Binary search may miss equal entries if the entries are not sorted.
+            // Since insertion is `O(n)` anyways, we accept the additional cost.
+            Err(index) => match self.index_of(&element) {
+                Some(n) => Err(n),
+                None => {
+                    self.0.insert(index, element);
+                    Ok(index)
+                }
+            },
+        }
+    }
+
+    /// Clones and appends all elements of the given slice which are not already present.
+    /// Returns clones of all duplicates in order.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2]).0;
+    /// let duplicates = unique_vec.extend_from_slice(&[2, 3, 4, 3]);
+    /// assert_eq!(duplicates, vec![2, 3]);
+    /// assert_eq!(*unique_vec, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T]) -> Vec<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut duplicates = Vec::new();
+        for element in other {
+            if self.0.contains(element) {
+                duplicates.push(element.clone());
+            } else {
+                self.0.push(element.clone());
+            }
+        }
+        duplicates
+    }
+
+    /// Checks if every element of the given iterator is contained.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert!(unique_vec.contains_all(&[3, 1]));
+    /// assert!(!unique_vec.contains_all(&[1, 4]));
+    /// ```
+    pub fn contains_all<'a, I>(&self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        iter.into_iter().all(|element| self.0.contains(element))
+    }
+
+    /// Checks if any element of the given iterator is contained.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert!(unique_vec.contains_any(&[4, 1]));
+    /// assert!(!unique_vec.contains_any(&[4, 5]));
+    /// ```
+    pub fn contains_any<'a, I>(&self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        iter.into_iter().any(|element| self.0.contains(element))
+    }
+
+    /// Returns the positions of all elements of the given iterator in order.
+    /// Elements which are not present yield [None].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["a", "b", "c"].map(String::from)).0;
+    /// assert_eq!(unique_vec.positions(["c", "x", "a"]), vec![Some(2), None, Some(0)]);
+    /// ```
+    pub fn positions<'a, Q, I>(&self, iter: I) -> Vec<Option<usize>>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized + 'a,
+    {
+        iter.into_iter()
+            .map(|element| self.index_of(element))
+            .collect()
+    }
+
+    /// Removes all entries at the given positions in one pass.
+    /// Indices may be given in any order and may occur multiple times.
+    /// Returns the removed entries ordered by their previous position.
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// let removed = unique_vec.remove_indices(&[3, 0, 3]);
+    /// assert_eq!(removed, vec![1, 4]);
+    /// assert_eq!(*unique_vec, vec![2, 3, 5]);
+    /// ```
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Vec<T> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        if let Some(&index) = indices.last() {
+            let len = self.0.len();
+            assert!(index < len, "index (is {index}) should be < len (is {len})");
+        }
+        let mut indices = indices.into_iter().peekable();
+        let mut n = 0;
+        self.0
+            .extract_if(.., |_| {
+                let remove = indices.next_if_eq(&n).is_some();
+                n += 1;
+                remove
+            })
+            .collect()
+    }
+
+    /// Removes all entries which are equal to any element of the given iterator in one pass.
+    /// Returns the removed entries in the order of the given elements.
+    /// Elements for which no entry is present yield [None].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// let removed = unique_vec.remove_items(&[4, 7, 1, 4]);
+    /// assert_eq!(removed, vec![Some(4), None, Some(1), None]);
+    /// assert_eq!(*unique_vec, vec![2, 3, 5]);
+    /// ```
+    pub fn remove_items<'a, Q, I>(&mut self, iter: I) -> Vec<Option<T>>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized + 'a,
+    {
+        let positions = self.positions(iter);
+        let mut indices: Vec<usize> = positions.iter().flatten().copied().collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let mut removed: Vec<Option<T>> = self
+            .remove_indices(&indices)
+            .into_iter()
+            .map(Some)
+            .collect();
+        positions
+            .into_iter()
+            .map(|position| {
+                let index = position?;
+                let n = indices.binary_search(&index).ok()?;
+                removed[n].take()
+            })
+            .collect()
+    }
+
+    /// Reorders all entries in place such that the entry at position `i` afterwards was
+    /// previously located at position `permutation[i]`.
+    ///
+    /// # Panics
+    /// Panics if `permutation` is not a permutation of `0..len`.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter(["a", "b", "c", "d"]).0;
+    /// unique_vec.reorder_by_permutation(&[2, 0, 3, 1]);
+    /// assert_eq!(*unique_vec, vec!["c", "a", "d", "b"]);
+    /// ```
+    pub fn reorder_by_permutation(&mut self, permutation: &[usize]) {
+        let len = self.0.len();
+        assert_eq!(
+            permutation.len(),
+            len,
+            "permutation length should be equal to len"
+        );
+        let mut visited = alloc::vec![false; len];
+        for &index in permutation {
+            assert!(index < len, "index (is {index}) should be < len (is {len})");
+            assert!(!visited[index], "index (is {index}) occurs multiple times");
+            visited[index] = true;
+        }
+        visited.fill(false);
+        for start in 0..len {
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                let next = permutation[current];
+                if next == start {
+                    break;
+                }
+                self.0.swap(current, next);
+                current = next;
+            }
+        }
+    }
+
+    /// Reorders all entries which are also contained in `other` such that they follow the order
+    /// of `other`.
+    /// The shared entries occupy the same positions as before while all other entries are not
+    /// moved.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3, 4, 5]).0;
+    /// unique_vec.reorder_to_match(&[5, 7, 3, 1]);
+    /// assert_eq!(*unique_vec, vec![5, 2, 3, 4, 1]);
+    /// ```
+    pub fn reorder_to_match<'a, Q, I>(&mut self, other: I)
+    where
+        I: IntoIterator<Item = &'a Q>,
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized + 'a,
+    {
+        let len = self.0.len();
+        let mut shared = alloc::vec![false; len];
+        let order: Vec<usize> = self
+            .positions(other)
+            .into_iter()
+            .flatten()
+            .filter(|&index| !core::mem::replace(&mut shared[index], true))
+            .collect();
+        let mut permutation: Vec<usize> = (0..len).collect();
+        let slots = (0..len).filter(|&index| shared[index]);
+        for (slot, index) in slots.zip(order) {
+            permutation[slot] = index;
+        }
+        self.reorder_by_permutation(&permutation);
+    }
+
+    /// Similar to [UniqueVec::retain] but the predicate also obtains the position of every entry
+    /// prior to removal.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([10, 11, 12, 13, 14]).0;
+    /// unique_vec.retain_with_index(|index, x| index < 2 || x % 2 == 1);
+    /// assert_eq!(*unique_vec, vec![10, 11, 13]);
+    /// ```
+    pub fn retain_with_index<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &T) -> bool,
+    {
+        let mut index = 0;
+        self.0.retain(|element| {
+            let keep = f(index, element);
+            index += 1;
+            keep
+        })
+    }
+
+    /// Returns all elements of the given iterator which are already contained in order.
+    /// In contrast to [UniqueVec::extend_from_iter], the [UniqueVec] is not modified.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let other = [4, 3, 5, 1];
+    /// assert_eq!(unique_vec.common_with(&other), vec![&3, &1]);
+    /// ```
+    pub fn common_with<'a, Q, I>(&self, other: I) -> Vec<&'a Q>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        T: core::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized + 'a,
+    {
+        other
+            .into_iter()
+            .filter(|element| self.index_of(*element).is_some())
+            .collect()
+    }
+
+    /// Constructs a new [UniqueVec] from the given vector without discarding any elements.
+    /// If the vector contains duplicates, a [DuplicateError] with the first duplicate and its
+    /// position in the vector is returned.
+    ///
+    /// This function takes the role of a [TryFrom] implementation which cannot be provided
+    /// since it would conflict with the blanket implementation derived from [From].
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let unique_vec = UniqueVec::try_from_vec(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    ///
+    /// let err = UniqueVec::try_from_vec(vec![1, 2, 1, 2]).unwrap_err();
+    /// assert_eq!(err, DuplicateError { index: 2, value: 1 });
+    /// ```
+    pub fn try_from_vec(mut vec: Vec<T>) -> Result<Self, DuplicateError<T>>
+    where
+        T: PartialEq,
+    {
+        match (0..vec.len()).find(|&index| vec[..index].contains(&vec[index])) {
+            Some(index) => Err(DuplicateError {
+                index,
+                value: vec.swap_remove(index),
+            }),
+            None => Ok(Self(vec)),
+        }
+    }
+
+    /// Constructs a new [UniqueVec] from the given iterator without discarding any elements.
+    /// Stops at the first duplicate and returns it together with its position in the iterator
+    /// as a [DuplicateError].
+    /// The remaining elements are not consumed.
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let unique_vec = UniqueVec::try_from_iter([1, 2, 3]).unwrap();
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    ///
+    /// let mut iter = [1, 2, 1, 3].into_iter();
+    /// let err = UniqueVec::try_from_iter(&mut iter).unwrap_err();
+    /// assert_eq!(err, DuplicateError { index: 2, value: 1 });
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Result<Self, DuplicateError<T>>
+    where
+        T: PartialEq,
+    {
+        let mut new = Self::new();
+        for (index, element) in iter.into_iter().enumerate() {
+            if let Some(value) = new.push(element) {
+                return Err(DuplicateError { index, value });
+            }
+        }
+        Ok(new)
+    }
+
+    /// Constructs a new [UniqueVec] from the given vector without checking for duplicates.
+    /// See [UniqueVec::from_vec_debug_checked] for a variant which verifies uniqueness in debug
+    /// builds.
+    ///
+    /// # Safety
+    /// The caller must ensure that the vector does not contain any duplicates.
+    /// Otherwise, the behaviour of all other methods is unspecified.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let set = std::collections::BTreeSet::from([3, 1, 2]);
+    /// let unique_vec = unsafe { UniqueVec::from_vec_unchecked(set.into_iter().collect()) };
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// ```
+    pub unsafe fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+
+    /// Constructs a new [UniqueVec] from the given vector and verifies uniqueness with an
+    /// `O(n²)` assertion in debug builds only.
+    /// In release builds, this is identical to [UniqueVec::from_vec_unchecked].
+    ///
+    /// # Safety
+    /// The caller must ensure that the vector does not contain any duplicates.
+    /// Otherwise, the behaviour of all other methods is unspecified.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = unsafe { UniqueVec::from_vec_debug_checked(vec![1, 2, 3]) };
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// ```
+    pub unsafe fn from_vec_debug_checked(vec: Vec<T>) -> Self
+    where
+        T: PartialEq,
+    {
+        debug_assert!(
+            (0..vec.len()).all(|index| !vec[..index].contains(&vec[index])),
+            "vector should not contain any duplicates"
+        );
+        Self(vec)
+    }
+
+    /// Construct a new [UniqueVec] from a sorted iterator.
+    /// Similarly to [UniqueVec::from_iter], this function also returns the rest which was not
+    /// inserted.
+    /// As long as the input is sorted, every element only needs to be compared against its
+    /// predecessor which makes construction `O(n)`.
+    /// Unsorted input will still produce a valid [UniqueVec] but at the cost of the regular
+    /// `O(n²)` checks.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let (unique_vec, rest) = UniqueVec::from_sorted_iter([1, 1, 2, 3, 3, 3, 4]);
+    /// assert_eq!(*unique_vec, vec![1, 2, 3, 4]);
+    /// assert_eq!(rest, vec![1, 3, 3]);
+    ///
+    /// // Unsorted input still yields unique entries
+    /// let (unique_vec, rest) = UniqueVec::from_sorted_iter([1, 2, 1, 3]);
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// assert_eq!(rest, vec![1]);
+    /// ```
+    pub fn from_sorted_iter(iter: impl IntoIterator<Item = T>) -> (Self, Vec<T>)
+    where
+        T: Ord,
+    {
+        let mut new_inner: Vec<T> = Vec::new();
+        let mut rest = Vec::new();
+        let mut sorted = true;
+        for element in iter {
+            let is_new = match new_inner.last() {
+                None => true,
+                Some(last) if sorted && *last < element => true,
+                Some(last) if *last == element => false,
+                _ => {
+                    sorted = false;
+                    !new_inner.contains(&element)
+                }
+            };
+            if is_new {
+                new_inner.push(element);
+            } else {
+                rest.push(element);
+            }
+        }
+        (Self(new_inner), rest)
+    }
+
+    /// Construct a new [UniqueVec] from a sorted vector.
+    /// See [UniqueVec::from_sorted_iter].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let (unique_vec, rest) = UniqueVec::from_sorted_vec(vec!["a", "b", "b", "c"]);
+    /// assert_eq!(*unique_vec, vec!["a", "b", "c"]);
+    /// assert_eq!(rest, vec!["b"]);
+    /// ```
+    pub fn from_sorted_vec(vec: Vec<T>) -> (Self, Vec<T>)
+    where
+        T: Ord,
+    {
+        Self::from_sorted_iter(vec)
+    }
+
+    /// Consumes the [UniqueVec] and returns the inner vector.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let vec: Vec<_> = unique_vec.into_vec();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Consumes the [UniqueVec] and returns its entries as a boxed slice.
+    /// Any excess capacity is dropped.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let boxed: Box<[_]> = unique_vec.into_boxed_slice();
+    /// assert_eq!(&*boxed, &[1, 2, 3]);
+    /// ```
+    pub fn into_boxed_slice(self) -> alloc::boxed::Box<[T]> {
+        self.0.into_boxed_slice()
+    }
+
+    /// Consumes the [UniqueVec] and returns its entries as a sorted vector.
+    /// Since all entries are unique, the result is strictly increasing.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([3, 1, 2]).0;
+    /// assert_eq!(unique_vec.into_sorted_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        self.0.sort_unstable();
+        self.0
+    }
+
+    /// Reserves capacity for at least `additional` more entries.
+    /// See [Vec::reserve].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1]).0;
+    /// unique_vec.reserve(10);
+    /// assert!(unique_vec.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more entries.
+    /// See [Vec::reserve_exact].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1]).0;
+    /// unique_vec.reserve_exact(10);
+    /// assert!(unique_vec.capacity() >= 11);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Shrinks the capacity as much as possible.
+    /// See [Vec::shrink_to_fit].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::with_capacity(10);
+    /// unique_vec.push(1);
+    /// unique_vec.shrink_to_fit();
+    /// assert!(unique_vec.capacity() >= 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity with a lower bound.
+    /// See [Vec::shrink_to].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::with_capacity(10);
+    /// unique_vec.push(1);
+    /// unique_vec.shrink_to(4);
+    /// assert!(unique_vec.capacity() >= 4);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.0.shrink_to(min_capacity)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more entries.
+    /// See [Vec::try_reserve].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1]).0;
+    /// assert!(unique_vec.try_reserve(10).is_ok());
+    /// assert!(unique_vec.capacity() >= 11);
+    /// assert!(unique_vec.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), alloc::collections::TryReserveError> {
+        self.0.try_reserve(additional)
+    }
+
+    /// Tries to reserve the minimum capacity for at least `additional` more entries.
+    /// See [Vec::try_reserve_exact].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1]).0;
+    /// assert!(unique_vec.try_reserve_exact(10).is_ok());
+    /// assert!(unique_vec.capacity() >= 11);
+    /// assert!(unique_vec.try_reserve_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_exact(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), alloc::collections::TryReserveError> {
+        self.0.try_reserve_exact(additional)
+    }
+
+    /// Consumes and leaks the [UniqueVec], returning a reference to its entries.
+    /// See [Vec::leak].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let entries: &'static [usize] = unique_vec.leak();
+    /// assert_eq!(entries, &[1, 2, 3]);
+    /// ```
+    pub fn leak<'a>(self) -> &'a [T] {
+        self.0.leak()
+    }
+
+    /// Clones all entries into a new [Vec].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let vec: Vec<_> = unique_vec.to_vec();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+
+    /// Returns a mutable reference to the inner vector.
+    ///
+    /// # Safety
+    /// The caller must ensure that the vector does not contain any duplicates once the
+    /// returned reference is dropped.
+    /// Otherwise, the behaviour of all other methods is unspecified.
+    /// For a safe alternative, see [UniqueVec::iter_mut] or [UniqueVec::get_mut].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// unsafe {
+    ///     let inner = unique_vec.as_mut_vec();
+    ///     inner[0] = 10;
+    ///     inner.push(20);
+    /// }
+    /// assert_eq!(*unique_vec, vec![10, 2, 3, 20]);
+    /// ```
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+
+    /// Gives mutable access to the inner vector within the given closure.
+    /// Afterwards, the [UniqueVec] is deduplicated again such that only the first occurrence of
+    /// every entry is kept.
+    /// Returns all removed entries in order.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let mut unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let removed = unique_vec.with_vec_mut(|inner| {
+    ///     inner.insert(0, 3);
+    ///     inner.push(4);
+    /// });
+    /// assert_eq!(removed, vec![3]);
+    /// assert_eq!(*unique_vec, vec![3, 1, 2, 4]);
+    ///
+    /// // The entries are also deduplicated if the closure panics
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     unique_vec.with_vec_mut(|inner| {
+    ///         inner.push(1);
+    ///         panic!();
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(*unique_vec, vec![3, 1, 2, 4]);
+    /// ```
+    pub fn with_vec_mut<F>(&mut self, f: F) -> Vec<T>
+    where
+        F: FnOnce(&mut Vec<T>),
+        T: PartialEq,
+    {
+        let mut guard = self.iter_mut();
+        f(&mut guard.vec);
+        guard.finish()
+    }
+
+    /// Decomposes the [UniqueVec] into the raw pointer, length and capacity of the inner vector.
+    /// The caller becomes responsible for the memory which can be reclaimed by
+    /// [UniqueVec::from_raw_parts].
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// let (ptr, len, cap) = unique_vec.into_raw_parts();
+    /// let unique_vec = unsafe { UniqueVec::from_raw_parts(ptr, len, cap) };
+    /// assert_eq!(*unique_vec, vec![1, 2, 3]);
+    /// ```
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let mut inner = core::mem::ManuallyDrop::new(self.0);
+        (inner.as_mut_ptr(), inner.len(), inner.capacity())
+    }
+
+    /// Creates a [UniqueVec] directly from the raw parts of a vector.
+    ///
+    /// # Safety
+    /// All safety requirements of [Vec::from_raw_parts] must be met.
+    /// In addition, the caller must ensure that the elements do not contain any duplicates.
+    /// Otherwise, the behaviour of all other methods is unspecified.
+    pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
+        Self(unsafe { Vec::from_raw_parts(ptr, length, capacity) })
+    }
+
+    /// Returns a view of all entries which is guaranteed to only contain unique entries.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.as_unique_slice(), &[1, 2, 3][..]);
+    /// ```
+    pub fn as_unique_slice(&self) -> &UniqueSlice<T> {
+        UniqueSlice::from_slice(&self.0)
+    }
+
+    /// Returns a view of all entries in the given range or [None] if it is out of bounds.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.get_range(1..).unwrap(), &[2, 3][..]);
+    /// assert!(unique_vec.get_range(2..4).is_none());
+    /// ```
+    pub fn get_range<R>(&self, range: R) -> Option<&UniqueSlice<T>>
+    where
+        R: core::slice::SliceIndex<[T], Output = [T]>,
+    {
+        self.0.get(range).map(UniqueSlice::from_slice)
+    }
+
+    /// Formats all entries and joins them with the given separator.
+    /// In contrast to [slice::join], the entries only need to implement
+    /// [Display](core::fmt::Display).
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+    /// assert_eq!(unique_vec.join_display(", "), "1, 2, 3");
+    ///
+    /// // The slice method is still available
+    /// let unique_vec = UniqueVec::from_iter([vec![1], vec![2]]).0;
+    /// assert_eq!(unique_vec.join(&0), vec![1, 0, 2]);
+    /// ```
+    pub fn join_display(&self, separator: &str) -> alloc::string::String
+    where
+        T: core::fmt::Display,
+    {
+        use alloc::string::ToString;
+        self.display(separator).to_string()
+    }
+
+    /// Returns an adapter which implements [Display](core::fmt::Display) by formatting all
+    /// entries joined with the given separator.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::from_iter(["red", "green"]).0;
+    /// assert_eq!(format!("[{}]", unique_vec.display(" | ")), "[red | green]");
+    /// ```
+    pub fn display<'a>(&'a self, separator: &'a str) -> Joined<'a, T>
+    where
+        T: core::fmt::Display,
+    {
+        Joined {
+            entries: &self.0,
+            separator,
+        }
+    }
+
+    /// Parses all entries from a string which are separated by the given delimiter.
+    /// Surrounding whitespace of every entry is trimmed and an empty string produces an empty
+    /// [UniqueVec].
+    /// Duplicates are silently dropped.
+    ///
+    /// ```
+    /// # use uniquevec::UniqueVec;
+    /// let unique_vec = UniqueVec::<u8>::parse_delimited("1; 2; 1; 3", ";").unwrap();
+    /// assert_eq!(unique_vec, [1, 2, 3]);
+    /// assert!(UniqueVec::<u8>::parse_delimited("1; x", ";").is_err());
+    /// ```
+    pub fn parse_delimited(s: &str, delimiter: &str) -> Result<Self, ParseError<T, T::Err>>
+    where
+        T: core::str::FromStr + PartialEq,
+    {
+        let mut new = Self::new();
+        for element in parse_entries(s, delimiter) {
+            new.push(element?);
+        }
+        Ok(new)
+    }
+
+    /// Similar to [UniqueVec::parse_delimited] but fails at the first duplicate.
+    ///
+    /// ```
+    /// # use uniquevec::*;
+    /// let unique_vec = UniqueVec::<u8>::parse_delimited_strict("1 2 3", " ").unwrap();
+    /// assert_eq!(unique_vec, [1, 2, 3]);
+    ///
+    /// let err = UniqueVec::<u8>::parse_delimited_strict("1 2 1", " ").unwrap_err();
+    /// assert_eq!(err, ParseError::Duplicate(DuplicateError { index: 2, value: 1 }));
+    /// ```
+    pub fn parse_delimited_strict(s: &str, delimiter: &str) -> Result<Self, ParseError<T, T::Err>>
+    where
+        T: core::str::FromStr + PartialEq,
+    {
+        let mut new = Self::new();
+        for (index, element) in parse_entries(s, delimiter).enumerate() {
+            if let Some(value) = new.push(element?) {
+                return Err(ParseError::Duplicate(DuplicateError { index, value }));
+            }
+        }
+        Ok(new)
+    }
+}
+
+impl<T> core::iter::Extend<T> for UniqueVec<T>
+where
+    T: PartialEq,
+{
+    fn extend<S: IntoIterator<Item = T>>(&mut self, iter: S) {
+        self.extend_from_iter(iter);
+    }
+}
+
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let mut unique_vec = UniqueVec::from_iter([1, 2]).0;
+/// unique_vec.extend([2, 3, 3].iter());
+/// assert_eq!(unique_vec, [1, 2, 3]);
+/// ```
+impl<'a, T> core::iter::Extend<&'a T> for UniqueVec<T>
+where
+    T: Copy + PartialEq + 'a,
+{
+    fn extend<S: IntoIterator<Item = &'a T>>(&mut self, iter: S) {
+        self.extend_from_iter(iter.into_iter().copied());
+    }
+}
+
+/// Collects all elements into a [UniqueVec] while silently dropping duplicates.
+/// Use [UniqueVec::from_iter] to obtain the dropped elements.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec: UniqueVec<_> = [1, 2, 1, 3].into_iter().collect();
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+/// ```
+impl<T> FromIterator<T> for UniqueVec<T>
+where
+    T: PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        UniqueVec::from_iter(iter).0
+    }
+}
+
+/// Parses comma-separated entries while silently dropping duplicates.
+/// See [UniqueVec::parse_delimited] and [UniqueVec::parse_delimited_strict] for other
+/// delimiters and strict handling of duplicates.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let colors: UniqueVec<String> = "red, green, blue, red".parse().unwrap();
+/// assert_eq!(colors, ["red", "green", "blue"].map(String::from));
+///
+/// let empty: UniqueVec<u8> = "".parse().unwrap();
+/// assert!(empty.is_empty());
+/// ```
+impl<T> core::str::FromStr for UniqueVec<T>
+where
+    T: core::str::FromStr + PartialEq,
+{
+    type Err = ParseError<T, T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_delimited(s, ",")
+    }
+}
+
+/// The [Clone::clone_from] implementation reuses the existing allocation.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let source = UniqueVec::from_iter([1, 2, 3]).0;
+/// let mut target = UniqueVec::with_capacity(16);
+/// target.clone_from(&source);
+/// assert_eq!(*target, vec![1, 2, 3]);
+/// assert!(target.capacity() >= 16);
+/// ```
+impl<T> Clone for UniqueVec<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0)
+    }
+}
+
+macro_rules! impl_partial_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                self.iter().eq(other.iter())
+            }
+        }
+    };
+}
+
+impl_partial_eq! { [] UniqueVec<T>, Vec<U> }
+impl_partial_eq! { [] UniqueVec<T>, [U] }
+impl_partial_eq! { [] UniqueVec<T>, &[U] }
+impl_partial_eq! { [] UniqueVec<T>, &mut [U] }
+impl_partial_eq! { [const N: usize] UniqueVec<T>, [U; N] }
+impl_partial_eq! { [const N: usize] UniqueVec<T>, &[U; N] }
+impl_partial_eq! { [] Vec<T>, UniqueVec<U> }
+impl_partial_eq! { [] [T], UniqueVec<U> }
+impl_partial_eq! { [] &[T], UniqueVec<U> }
+impl_partial_eq! { [] &mut [T], UniqueVec<U> }
+impl_partial_eq! { [const N: usize] [T; N], UniqueVec<U> }
+impl_partial_eq! { [const N: usize] &[T; N], UniqueVec<U> }
+
+impl<T> AsRef<[T]> for UniqueVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> AsRef<Vec<T>> for UniqueVec<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use std::borrow::Borrow;
+/// fn sum(values: impl AsRef<[u32]>) -> u32 {
+///     values.as_ref().iter().sum()
+/// }
+/// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+/// let slice: &[u32] = unique_vec.borrow();
+/// assert_eq!(slice, &[1, 2, 3]);
+/// assert_eq!(sum(unique_vec), 6);
+/// ```
+impl<T> core::borrow::Borrow<[T]> for UniqueVec<T> {
+    fn borrow(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> Default for UniqueVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::ops::Deref for UniqueVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Constructs a new [UniqueVec] from a vector while silently dropping duplicates.
+/// The allocation of the vector is reused.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let vec = vec![1, 2, 1, 3, 2];
+/// let ptr = vec.as_ptr();
+/// let unique_vec = UniqueVec::from(vec);
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+/// assert_eq!(unique_vec.as_ptr(), ptr);
+/// ```
+impl<T> From<Vec<T>> for UniqueVec<T>
+where
+    T: PartialEq,
+{
+    fn from(mut value: Vec<T>) -> Self {
+        let n_unique = dedup_slice(&mut value);
+        value.truncate(n_unique);
+        Self(value)
+    }
+}
+
+/// Constructs a new [UniqueVec] by cloning the elements of a slice while silently dropping
+/// duplicates.
+/// Only the elements which are kept are cloned.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec = UniqueVec::from(&[1, 2, 1, 3][..]);
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+/// ```
+impl<T> From<&[T]> for UniqueVec<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from(value: &[T]) -> Self {
+        let mut new_inner = Vec::new();
+        for element in value {
+            if !new_inner.contains(element) {
+                new_inner.push(element.clone());
+            }
+        }
+        Self(new_inner)
+    }
+}
+
+impl<T> From<UniqueVec<T>> for Vec<T> {
+    fn from(value: UniqueVec<T>) -> Self {
+        value.0
+    }
+}
+
+/// Constructs a new [UniqueVec] from a [BTreeSet](alloc::collections::BTreeSet) while
+/// preserving its sorted order.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// # use std::collections::BTreeSet;
+/// let set = BTreeSet::from([3, 1, 2]);
+/// let unique_vec = UniqueVec::from(set);
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+///
+/// let set: BTreeSet<_> = unique_vec.into();
+/// assert_eq!(set, BTreeSet::from([1, 2, 3]));
+/// ```
+impl<T> From<alloc::collections::BTreeSet<T>> for UniqueVec<T> {
+    fn from(value: alloc::collections::BTreeSet<T>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+impl<T> From<UniqueVec<T>> for alloc::collections::BTreeSet<T>
+where
+    T: Ord,
+{
+    fn from(value: UniqueVec<T>) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+/// Constructs a new [UniqueVec] from an `IndexSet` while preserving its order.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use indexmap::IndexSet;
+/// let mut set = IndexSet::with_hasher(std::hash::RandomState::new());
+/// set.extend([3, 1, 2]);
+/// let unique_vec = UniqueVec::from(set.clone());
+/// assert_eq!(*unique_vec, vec![3, 1, 2]);
+/// assert_eq!(unique_vec, set);
+///
+/// let other: IndexSet<_, std::hash::RandomState> = unique_vec.into();
+/// assert_eq!(other, set);
+/// ```
+#[cfg(feature = "indexmap")]
+impl<T, S> From<indexmap::IndexSet<T, S>> for UniqueVec<T> {
+    fn from(value: indexmap::IndexSet<T, S>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> From<UniqueVec<T>> for indexmap::IndexSet<T, S>
+where
+    T: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    fn from(value: UniqueVec<T>) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+/// Compares all entries in order.
+/// Note that this differs from comparing two `IndexSet`s which disregards order.
+#[cfg(feature = "indexmap")]
+impl<T, S> PartialEq<indexmap::IndexSet<T, S>> for UniqueVec<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &indexmap::IndexSet<T, S>) -> bool {
+        self.0.len() == other.len() && self.0.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+/// Compares all entries in order.
+/// Note that this differs from comparing two `IndexSet`s which disregards order.
+#[cfg(feature = "indexmap")]
+impl<T, S> PartialEq<UniqueVec<T>> for indexmap::IndexSet<T, S>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &UniqueVec<T>) -> bool {
+        other == self
+    }
+}
+
+/// Constructs a new [UniqueVec] from a [HashSet](std::collections::HashSet).
+/// The order of the entries is unspecified.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// # use std::collections::HashSet;
+/// let set = HashSet::from([1, 2, 3]);
+/// let mut unique_vec = UniqueVec::from(set);
+/// unique_vec.sort();
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+///
+/// let set: HashSet<_> = unique_vec.into();
+/// assert_eq!(set, HashSet::from([1, 2, 3]));
+/// ```
+#[cfg(feature = "std")]
+impl<T, S> From<std::collections::HashSet<T, S>> for UniqueVec<T> {
+    fn from(value: std::collections::HashSet<T, S>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> From<UniqueVec<T>> for std::collections::HashSet<T, S>
+where
+    T: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    fn from(value: UniqueVec<T>) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+/// Deserializes a [UniqueVec] from a sequence and rejects duplicates.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec: UniqueVec<u8> = serde_json::from_str("[1, 2, 3]").unwrap();
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+///
+/// let err = serde_json::from_str::<UniqueVec<u8>>("[1, 2, 1]").unwrap_err();
+/// assert!(err.to_string().contains("duplicate entry at index 2"));
+///
+/// // Deserializing in place reuses the existing allocation
+/// use serde::Deserialize;
+/// let mut unique_vec: UniqueVec<u8> = UniqueVec::with_capacity(10);
+/// let mut deserializer = serde_json::Deserializer::from_str("[4, 5]");
+/// UniqueVec::deserialize_in_place(&mut deserializer, &mut unique_vec).unwrap();
+/// assert_eq!(*unique_vec, vec![4, 5]);
+/// assert!(unique_vec.capacity() >= 10);
+/// ```
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for UniqueVec<T>
+where
+    T: Deserialize<'de> + PartialEq,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::new(false))
+    }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(InPlaceVisitor(place))
+    }
+}
+
+/// Checks every entry for uniqueness as soon as it is deserialized
+/// such that duplicates are never stored.
+#[cfg(feature = "serde")]
+struct SeqVisitor<T> {
+    lossy: bool,
+    marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> SeqVisitor<T> {
+    fn new(lossy: bool) -> Self {
+        Self {
+            lossy,
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for SeqVisitor<T>
+where
+    T: Deserialize<'de> + PartialEq,
+{
+    type Value = UniqueVec<T>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a sequence of unique entries")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        // Do not trust the size hint blindly to avoid allocating excessive memory
+        let capacity = seq.size_hint().unwrap_or(0).min(4096);
+        let mut unique_vec = UniqueVec(Vec::with_capacity(capacity));
+        visit_entries(&mut seq, &mut unique_vec, self.lossy)?;
+        Ok(unique_vec)
+    }
+}
+
+/// Deserializes into an existing [UniqueVec] while reusing its allocation.
+#[cfg(feature = "serde")]
+struct InPlaceVisitor<'a, T>(&'a mut UniqueVec<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for InPlaceVisitor<'_, T>
+where
+    T: Deserialize<'de> + PartialEq,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a sequence of unique entries")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        self.0.clear();
+        visit_entries(&mut seq, self.0, false)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn visit_entries<'de, A, T>(
+    seq: &mut A,
+    unique_vec: &mut UniqueVec<T>,
+    lossy: bool,
+) -> Result<(), A::Error>
+where
+    A: serde::de::SeqAccess<'de>,
+    T: Deserialize<'de> + PartialEq,
+{
+    let mut index = 0;
+    while let Some(value) = seq.next_element()? {
+        if let Some(value) = unique_vec.push(value) {
+            if !lossy {
+                return Err(serde::de::Error::custom(DuplicateError { index, value }));
+            }
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+/// Lossy (de)serialization of a [UniqueVec] for use with `#[serde(with = "uniquevec::lossy")]`.
+///
+/// In contrast to the [Deserialize] implementation of [UniqueVec], duplicates are silently
+/// dropped and only their first occurrence is kept.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Config {
+///     #[serde(with = "uniquevec::lossy")]
+///     names: UniqueVec<String>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"names": ["a", "b", "a"]}"#).unwrap();
+/// assert_eq!(*config.names, vec!["a", "b"]);
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"names":["a","b"]}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod lossy {
+    use super::*;
+
+    /// Serializes the [UniqueVec] as a plain sequence.
+    pub fn serialize<T, S>(value: &UniqueVec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: serde::Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a sequence while keeping only the first occurrence of every entry.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<UniqueVec<T>, D::Error>
+    where
+        T: Deserialize<'de> + PartialEq,
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::new(true))
+    }
+}
+
+/// [serde_with](https://docs.rs/serde_with) adapter which uses the (de)serialization of a
+/// [UniqueVec] for plain [Vec] fields and thus rejects duplicates.
+///
+/// ```
+/// # use uniquevec::{UniqueSeq, UniqueSeqLossy};
+/// use serde_with::As;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "As::<UniqueSeq>")]
+///     strict: Vec<u8>,
+///     #[serde(with = "As::<UniqueSeqLossy>")]
+///     lossy: Vec<u8>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"strict": [1, 2], "lossy": [1, 1]}"#).unwrap();
+/// assert_eq!(config.strict, vec![1, 2]);
+/// assert_eq!(config.lossy, vec![1]);
+/// assert!(serde_json::from_str::<Config>(r#"{"strict": [1, 1], "lossy": []}"#).is_err());
+/// ```
+#[cfg(feature = "serde_with")]
+pub struct UniqueSeq;
+
+/// [serde_with](https://docs.rs/serde_with) adapter which silently drops duplicates of plain
+/// [Vec] fields. See [UniqueSeq].
+#[cfg(feature = "serde_with")]
+pub struct UniqueSeqLossy;
+
+#[cfg(feature = "serde_with")]
+impl<T> serde_with::SerializeAs<Vec<T>> for UniqueSeq
+where
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        source.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de, T> serde_with::DeserializeAs<'de, Vec<T>> for UniqueSeq
+where
+    T: Deserialize<'de> + PartialEq,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        UniqueVec::deserialize(deserializer).map(UniqueVec::into_vec)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<T> serde_with::SerializeAs<Vec<T>> for UniqueSeqLossy
+where
+    T: Serialize,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        source.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de, T> serde_with::DeserializeAs<'de, Vec<T>> for UniqueSeqLossy
+where
+    T: Deserialize<'de> + PartialEq,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        lossy::deserialize(deserializer).map(UniqueVec::into_vec)
+    }
+}
+
+/// Validating an archived [UniqueVec] also verifies that all of its entries are unique.
+///
+/// ```
+/// # use uniquevec::{ArchivedUniqueVec, UniqueVec};
+/// use rkyv::rancor::Error;
+/// let unique_vec = UniqueVec::from_iter([1_u32, 2, 3]).0;
+/// let bytes = rkyv::to_bytes::<Error>(&unique_vec).unwrap();
+/// let archived = rkyv::access::<ArchivedUniqueVec<u32>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.len(), 3);
+///
+/// let deserialized = rkyv::deserialize::<UniqueVec<u32>, Error>(archived).unwrap();
+/// assert_eq!(deserialized, unique_vec);
+///
+/// // Archived data which contains duplicates is rejected
+/// let bytes = rkyv::to_bytes::<Error>(&vec![1_u32, 2, 1]).unwrap();
+/// assert!(rkyv::access::<ArchivedUniqueVec<u32>, Error>(&bytes).is_err());
+/// ```
+#[cfg(feature = "rkyv")]
+unsafe impl<T, C> rkyv::bytecheck::Verify<C> for ArchivedUniqueVec<T>
+where
+    T: rkyv::Archive,
+    T::Archived: PartialEq,
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        let entries = self.0.as_slice();
+        match (0..entries.len()).find(|&index| entries[..index].contains(&entries[index])) {
+            Some(index) => Err(rkyv::rancor::Source::new(DuplicateError {
+                index,
+                value: (),
+            })),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T> core::ops::Deref for ArchivedUniqueVec<T>
+where
+    T: rkyv::Archive,
+{
+    type Target = [T::Archived];
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_slice()
+    }
+}
+
+/// Serializes a [UniqueVec] identically to a [Vec].
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec = UniqueVec::from_iter([1_u8, 2, 3]).0;
+/// let bytes = borsh::to_vec(&unique_vec).unwrap();
+/// assert_eq!(bytes, borsh::to_vec(&vec![1_u8, 2, 3]).unwrap());
+/// assert_eq!(borsh::from_slice::<UniqueVec<u8>>(&bytes).unwrap(), unique_vec);
+///
+/// // Deserialization rejects duplicates
+/// let bytes = borsh::to_vec(&vec![1_u8, 2, 1]).unwrap();
+/// assert!(borsh::from_slice::<UniqueVec<u8>>(&bytes).is_err());
+/// ```
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshSerialize for UniqueVec<T>
+where
+    T: borsh::BorshSerialize,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshDeserialize for UniqueVec<T>
+where
+    T: borsh::BorshDeserialize + PartialEq,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let vec = Vec::<T>::deserialize_reader(reader)?;
+        Self::try_from_vec(vec).map_err(|err| {
+            borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                alloc::string::ToString::to_string(&err),
+            )
+        })
+    }
+}
+
+/// Describes a [UniqueVec] as an array with `uniqueItems` set.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let schema = schemars::schema_for!(UniqueVec<String>);
+/// assert_eq!(schema.get("type"), Some(&"array".into()));
+/// assert_eq!(schema.get("uniqueItems"), Some(&true.into()));
+/// ```
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for UniqueVec<T>
+where
+    T: schemars::JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        alloc::format!("UniqueVec_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        alloc::format!("uniquevec::UniqueVec<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "array",
+            "uniqueItems": true,
+            "items": generator.subschema_for::<T>(),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for UniqueVecEq<T>
+where
+    T: schemars::JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        UniqueVec::<T>::schema_name()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        UniqueVec::<T>::schema_id()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        UniqueVec::<T>::json_schema(generator)
+    }
+}
+
+/// Encodes a [UniqueVec] identically to a [Vec].
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let config = bincode::config::standard();
+/// let unique_vec = UniqueVec::from_iter([1_u32, 2, 3]).0;
+/// let bytes = bincode::encode_to_vec(&unique_vec, config).unwrap();
+/// let (decoded, _): (UniqueVec<u32>, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+/// assert_eq!(decoded, unique_vec);
+///
+/// // Decoding rejects duplicates
+/// let bytes = bincode::encode_to_vec(vec![1_u32, 2, 1], config).unwrap();
+/// assert!(bincode::decode_from_slice::<UniqueVec<u32>, _>(&bytes, config).is_err());
+/// ```
+#[cfg(feature = "bincode")]
+impl<T> bincode::Encode for UniqueVec<T>
+where
+    T: bincode::Encode,
+{
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context, T> bincode::Decode<Context> for UniqueVec<T>
+where
+    T: bincode::Decode<Context> + PartialEq,
+{
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let vec = Vec::<T>::decode(decoder)?;
+        Self::try_from_vec(vec).map_err(|err| {
+            bincode::error::DecodeError::OtherString(alloc::string::ToString::to_string(&err))
+        })
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, T> bincode::BorrowDecode<'de, Context> for UniqueVec<T>
+where
+    T: bincode::BorrowDecode<'de, Context> + PartialEq,
+{
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let vec = Vec::<T>::borrow_decode(decoder)?;
+        Self::try_from_vec(vec).map_err(|err| {
+            bincode::error::DecodeError::OtherString(alloc::string::ToString::to_string(&err))
+        })
+    }
+}
+
+/// Formats a [UniqueVec] like a slice of its entries.
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for UniqueVec<T>
+where
+    T: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        self.0.as_slice().format(fmt)
+    }
+}
+
+/// Formats a [UniqueVecEq] like a slice of its entries.
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for UniqueVecEq<T>
+where
+    T: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        self.0.format(fmt)
+    }
+}
+
+/// Wipes all entries as well as the spare capacity and leaves the [UniqueVec] empty.
+///
+/// To wipe the entries automatically when dropping, wrap the [UniqueVec] in
+/// `zeroize::Zeroizing` which implements `ZeroizeOnDrop`.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use zeroize::{Zeroize, Zeroizing};
+/// let mut unique_vec = UniqueVec::from_iter([1_u8, 2, 3]).0;
+/// unique_vec.zeroize();
+/// assert!(unique_vec.is_empty());
+///
+/// let tokens = Zeroizing::new(UniqueVec::from_iter([*b"token-a", *b"token-b"]).0);
+/// assert_eq!(tokens.len(), 2);
+/// ```
+#[cfg(feature = "zeroize")]
+impl<T> zeroize::Zeroize for UniqueVec<T>
+where
+    T: zeroize::Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> zeroize::Zeroize for UniqueVecEq<T>
+where
+    T: zeroize::Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// Generates a [UniqueVec] directly by skipping generated duplicates.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use arbitrary::{Arbitrary, Unstructured};
+/// let bytes = [1, 0, 1, 1, 1, 2, 1, 0, 0];
+/// let mut u = Unstructured::new(&bytes);
+/// let unique_vec = UniqueVec::<u8>::arbitrary(&mut u).unwrap();
+/// assert_eq!(*unique_vec, vec![0, 1, 2]);
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for UniqueVec<T>
+where
+    T: arbitrary::Arbitrary<'a> + PartialEq,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// Generates and shrinks a [UniqueVec] by deduplicating generated and shrunk vectors.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use quickcheck::{Arbitrary, Gen};
+/// let unique_vec = UniqueVec::<u8>::arbitrary(&mut Gen::new(100));
+/// assert_eq!(unique_vec, UniqueVec::from(unique_vec.to_vec()));
+///
+/// let unique_vec = UniqueVec::from_iter([3_u8, 7, 1]).0;
+/// for shrunk in unique_vec.shrink() {
+///     assert!(shrunk.len() <= unique_vec.len());
+///     assert_eq!(shrunk, UniqueVec::from(shrunk.to_vec()));
+/// }
+/// ```
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for UniqueVec<T>
+where
+    T: quickcheck::Arbitrary + PartialEq,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from(Vec::<T>::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(Self::from))
+    }
+}
+
+/// [proptest](https://docs.rs/proptest) strategies which generate and shrink [UniqueVec]s.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// use proptest::prelude::*;
+/// use proptest::test_runner::{TestError, TestRunner};
+///
+/// let mut runner = TestRunner::default();
+/// let strategy = uniquevec::proptest::unique_vec(0_u8..10, 2..5);
+/// runner
+///     .run(&strategy, |unique_vec| {
+///         prop_assert!(unique_vec.len() >= 2 && unique_vec.len() < 5);
+///         prop_assert_eq!(&unique_vec, &UniqueVec::from(unique_vec.to_vec()));
+///         Ok(())
+///     })
+///     .unwrap();
+///
+/// // Failing cases are shrunk while keeping all entries unique
+/// let result = TestRunner::default().run(&any::<UniqueVec<u8>>(), |unique_vec| {
+///     prop_assert!(unique_vec.len() < 3);
+///     Ok(())
+/// });
+/// match result {
+///     Err(TestError::Fail(_, unique_vec)) => {
+///         assert_eq!(unique_vec.len(), 3);
+///         assert_eq!(unique_vec, UniqueVec::from(unique_vec.to_vec()));
+///     }
+///     _ => panic!("expected a failing case"),
+/// }
+/// ```
+#[cfg(feature = "proptest")]
+pub mod proptest {
+    use super::*;
+    use ::proptest::collection::{SizeRange, VecStrategy, VecValueTree};
+    use ::proptest::strategy::{statics, NewTree, Strategy, ValueTree};
+    use ::proptest::test_runner::TestRunner;
+
+    #[derive(Clone, Copy, Debug)]
+    struct VecToUniqueVec;
+
+    impl<T> statics::MapFn<Vec<T>> for VecToUniqueVec
+    where
+        T: PartialEq + core::fmt::Debug,
+    {
+        type Output = UniqueVec<T>;
+
+        fn apply(&self, vec: Vec<T>) -> UniqueVec<T> {
+            UniqueVec::from(vec)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct MinSize(usize);
+
+    impl<T> statics::FilterFn<UniqueVec<T>> for MinSize {
+        fn apply(&self, unique_vec: &UniqueVec<T>) -> bool {
+            unique_vec.len() >= self.0
+        }
+    }
+
+    /// Strategy which generates a [UniqueVec] with a length in a certain range.
+    ///
+    /// Created by the [unique_vec()] function.
+    #[derive(Clone, Debug)]
+    pub struct UniqueVecStrategy<S>(
+        statics::Filter<statics::Map<VecStrategy<S>, VecToUniqueVec>, MinSize>,
+    )
+    where
+        S: Strategy;
+
+    /// [ValueTree] of the [UniqueVecStrategy].
+    #[derive(Clone, Debug)]
+    pub struct UniqueVecValueTree<T>(
+        statics::Filter<statics::Map<VecValueTree<T>, VecToUniqueVec>, MinSize>,
+    )
+    where
+        T: ValueTree;
+
+    /// Creates a strategy which generates a [UniqueVec] with entries drawn from `element` and a
+    /// length within `size`.
+    ///
+    /// Duplicates produced by `element` are dropped.
+    /// Values which end up shorter than the minimum length are rejected locally.
+    pub fn unique_vec<S>(element: S, size: impl Into<SizeRange>) -> UniqueVecStrategy<S>
+    where
+        S: Strategy,
+        S::Value: PartialEq,
+    {
+        let size = size.into();
+        UniqueVecStrategy(statics::Filter::new(
+            statics::Map::new(
+                ::proptest::collection::vec(element, size.clone()),
+                VecToUniqueVec,
+            ),
+            "UniqueVec minimum size".into(),
+            MinSize(size.start()),
+        ))
+    }
+
+    impl<S> Strategy for UniqueVecStrategy<S>
+    where
+        S: Strategy,
+        S::Value: PartialEq,
+    {
+        type Tree = UniqueVecValueTree<S::Tree>;
+        type Value = UniqueVec<S::Value>;
+
+        fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+            self.0.new_tree(runner).map(UniqueVecValueTree)
+        }
+    }
+
+    impl<T> ValueTree for UniqueVecValueTree<T>
+    where
+        T: ValueTree,
+        T::Value: PartialEq,
+    {
+        type Value = UniqueVec<T::Value>;
+
+        fn current(&self) -> Self::Value {
+            self.0.current()
+        }
+
+        fn simplify(&mut self) -> bool {
+            self.0.simplify()
+        }
+
+        fn complicate(&mut self) -> bool {
+            self.0.complicate()
+        }
+    }
+
+    impl<T> ::proptest::arbitrary::Arbitrary for UniqueVec<T>
+    where
+        T: ::proptest::arbitrary::Arbitrary + PartialEq,
+    {
+        type Parameters = (SizeRange, T::Parameters);
+        type Strategy = UniqueVecStrategy<T::Strategy>;
+
+        fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+            unique_vec(::proptest::arbitrary::any_with::<T>(args), size)
+        }
+    }
+}
+
+/// Constructs a new [UniqueVec] from an array while silently dropping duplicates.
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec = UniqueVec::from([1, 2, 1, 3]);
+/// assert_eq!(*unique_vec, vec![1, 2, 3]);
+///
+/// // Strict conversion which rejects duplicates
+/// assert!(UniqueVec::try_from_vec([1, 2, 1].into()).is_err());
+/// ```
+impl<T, const N: usize> From<[T; N]> for UniqueVec<T>
+where
+    T: PartialEq,
+{
+    fn from(value: [T; N]) -> Self {
+        Self::from_iter(value).0
+    }
+}
+
+impl<T> IntoIterator for UniqueVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.0.into_iter())
+    }
+}
+
+/// ```
+/// # use uniquevec::UniqueVec;
+/// fn total<'a>(values: impl IntoIterator<Item = &'a u32>) -> u32 {
+///     values.into_iter().sum()
+/// }
+/// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+/// assert_eq!(total(&unique_vec), 6);
+/// for entry in &unique_vec {
+///     assert!(*entry > 0);
+/// }
+/// ```
+impl<'a, T> IntoIterator for &'a UniqueVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Removes all but the first occurrence of every element while preserving the order of the
+/// remaining elements.
+/// The removed elements are returned in their original order.
+fn dedup_vec<T>(vec: &mut Vec<T>) -> Vec<T>
+where
+    T: PartialEq,
+{
+    let n_unique = dedup_slice(vec);
+    vec.split_off(n_unique)
+}
+
+/// Moves the first occurrence of every element to the front while preserving their order.
+/// All duplicates are moved to the back while also preserving their order.
+/// Returns the number of unique elements.
+fn dedup_slice<T>(slice: &mut [T]) -> usize
+where
+    T: PartialEq,
+{
+    let mut n_unique = 0;
+    for i in 0..slice.len() {
+        if !slice[..n_unique].contains(&slice[i]) {
+            slice[n_unique..=i].rotate_right(1);
+            n_unique += 1;
+        }
+    }
+    n_unique
+}
+
+/// Guard which gives mutable access to a single entry of a [UniqueVec].
+///
+/// It is created by [UniqueVec::get_mut].
+/// When dropped, it checks if the modified entry collides with any other entry and removes it in
+/// this case.
+/// The entries are moved into the guard while it is alive.
+/// Leaking the guard, e.g. with [core::mem::forget], thus leaves the [UniqueVec] empty.
+pub struct EntryMut<'a, T>
+where
+    T: PartialEq,
+{
+    unique_vec: &'a mut UniqueVec<T>,
+    vec: Vec<T>,
+    index: usize,
+    finished: bool,
+}
+
+impl<T> EntryMut<'_, T>
+where
+    T: PartialEq,
+{
+    /// Position of the entry inside the [UniqueVec].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Consumes the guard and checks the modified entry for collisions.
+    /// If it is equal to any other entry, it is removed from the [UniqueVec] and returned.
+    pub fn finish(mut self) -> Option<T> {
+        self.validate().map(|(_, value)| value)
+    }
+
+    /// Removes the entry if it collides and moves all entries back into the [UniqueVec].
+    /// Returns the removed entry together with the current position of the entry it collided
+    /// with.
+    fn validate(&mut self) -> Option<(usize, T)> {
+        if core::mem::replace(&mut self.finished, true) {
+            return None;
+        }
+        let element = &self.vec[self.index];
+        let collision = self
+            .vec
+            .iter()
+            .enumerate()
+            .position(|(n, x)| n != self.index && x == element);
+        let removed = collision.map(|n| {
+            let value = self.vec.remove(self.index);
+            (if n > self.index { n - 1 } else { n }, value)
+        });
+        self.unique_vec.0 = core::mem::take(&mut self.vec);
+        removed
+    }
+}
+
+impl<T> core::ops::Deref for EntryMut<'_, T>
+where
+    T: PartialEq,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec[self.index]
+    }
+}
+
+impl<T> core::ops::DerefMut for EntryMut<'_, T>
+where
+    T: PartialEq,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec[self.index]
+    }
+}
+
+impl<T> Drop for EntryMut<'_, T>
+where
+    T: PartialEq,
+{
+    fn drop(&mut self) {
+        self.validate();
+    }
+}
+
+/// Guard which gives mutable access to all entries of a [UniqueVec].
+///
+/// It is created by [UniqueVec::iter_mut].
+/// When dropped, the [UniqueVec] is deduplicated such that only the first occurrence of every
+/// entry is kept.
+/// The entries are moved into the guard while it is alive.
+/// Leaking the guard, e.g. with [core::mem::forget], thus leaves the [UniqueVec] empty.
+pub struct IterMutGuard<'a, T>
+where
+    T: PartialEq,
+{
+    unique_vec: &'a mut UniqueVec<T>,
+    vec: Vec<T>,
+    finished: bool,
+}
+
+impl<T> IterMutGuard<'_, T>
+where
+    T: PartialEq,
+{
+    /// Iterates mutably over all entries.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.vec.iter_mut()
+    }
+
+    /// Consumes the guard and deduplicates the [UniqueVec].
+    /// Returns all removed entries in order.
+    pub fn finish(mut self) -> Vec<T> {
+        self.validate()
+    }
+
+    fn validate(&mut self) -> Vec<T> {
+        if core::mem::replace(&mut self.finished, true) {
+            return Vec::new();
+        }
+        let removed = dedup_vec(&mut self.vec);
+        self.unique_vec.0 = core::mem::take(&mut self.vec);
+        removed
+    }
+}
+
+impl<T> core::ops::Deref for IterMutGuard<'_, T>
+where
+    T: PartialEq,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T> core::ops::DerefMut for IterMutGuard<'_, T>
+where
+    T: PartialEq,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut IterMutGuard<'_, T>
+where
+    T: PartialEq,
+{
+    type Item = &'b mut T;
+    type IntoIter = core::slice::IterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Drop for IterMutGuard<'_, T>
+where
+    T: PartialEq,
+{
+    fn drop(&mut self) {
+        self.validate();
+    }
+}
+
+/// Guard which gives mutable access to multiple distinct entries of a [UniqueVec].
+///
+/// It is created by [UniqueVec::get_disjoint_mut].
+/// When dropped, every modified entry which collides with any other entry is removed.
+/// The entries are moved into the guard while it is alive.
+/// Leaking the guard, e.g. with [core::mem::forget], thus leaves the [UniqueVec] empty.
+pub struct DisjointMutGuard<'a, T, const N: usize>
+where
+    T: PartialEq,
+{
+    unique_vec: &'a mut UniqueVec<T>,
+    vec: Vec<T>,
+    indices: [usize; N],
+    finished: bool,
+}
+
+impl<T, const N: usize> DisjointMutGuard<'_, T, N>
+where
+    T: PartialEq,
+{
+    /// Positions of the entries inside the [UniqueVec].
+    pub fn indices(&self) -> [usize; N] {
+        self.indices
+    }
+
+    /// Mutable references to all entries in the order of the given indices.
+    pub fn entries(&mut self) -> [&mut T; N] {
+        self.vec
+            .get_disjoint_mut(self.indices)
+            .expect("indices were checked on construction")
+    }
+
+    /// Consumes the guard and checks the modified entries for collisions.
+    /// Returns all removed entries ordered by their previous position.
+    pub fn finish(mut self) -> Vec<T> {
+        self.validate()
+    }
+
+    fn validate(&mut self) -> Vec<T> {
+        if core::mem::replace(&mut self.finished, true) {
+            return Vec::new();
+        }
+        let inner = &mut self.vec;
+        let mut indices = self.indices;
+        indices.sort_unstable();
+        let collisions = indices.map(|i| {
+            inner
+                .iter()
+                .enumerate()
+                .any(|(n, x)| n != i && x == &inner[i] && (n < i || !indices.contains(&n)))
+        });
+        let mut removed: Vec<T> = indices
+            .iter()
+            .zip(collisions)
+            .rev()
+            .filter(|(_, collides)| *collides)
+            .map(|(&i, _)| inner.remove(i))
+            .collect();
+        removed.reverse();
+        self.unique_vec.0 = core::mem::take(&mut self.vec);
+        removed
+    }
+}
+
+impl<T, const N: usize> Drop for DisjointMutGuard<'_, T, N>
+where
+    T: PartialEq,
+{
+    fn drop(&mut self) {
+        self.validate();
+    }
+}
+
+/// Determines which occurrence of an element is kept by a [UniqueVecBuilder].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Keep the first occurrence and discard all later ones.
+    #[default]
+    KeepFirst,
+    /// Keep the last occurrence at its position and discard all earlier ones.
+    KeepLast,
+}
+
+/// Builder which accumulates elements and produces a [UniqueVec].
+/// All methods consume the builder and return it again such that calls can be chained.
+///
+/// ```
+/// # use uniquevec::*;
+/// let builder = UniqueVecBuilder::new()
+///     .capacity(4)
+///     .policy(DuplicatePolicy::KeepLast)
+///     .push(1)
+///     .push(2)
+///     .push(1)
+///     .extend([3, 2]);
+/// assert_eq!(builder.duplicates(), 2);
+/// let unique_vec = builder.build();
+/// assert_eq!(*unique_vec, vec![1, 3, 2]);
+///
+/// assert_eq!(*UniqueVecBuilder::new().push(1).build(), vec![1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UniqueVecBuilder<T> {
+    entries: Vec<T>,
+    policy: DuplicatePolicy,
+    duplicates: usize,
+}
+
+impl<T> UniqueVecBuilder<T>
+where
+    T: PartialEq,
+{
+    /// Creates a new empty [UniqueVecBuilder] with the [DuplicatePolicy::KeepFirst] policy.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            policy: DuplicatePolicy::default(),
+            duplicates: 0,
+        }
+    }
+
+    /// Reserves capacity for at least the given number of entries.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.entries.reserve(capacity);
+        self
+    }
+
+    /// Sets the [DuplicatePolicy] which is used for all subsequently added elements.
+    pub fn policy(mut self, policy: DuplicatePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Adds a new element according to the configured [DuplicatePolicy].
+    pub fn push(mut self, element: T) -> Self {
+        match self.entries.iter().position(|x| x == &element) {
+            Some(index) => {
+                self.duplicates += 1;
+                if self.policy == DuplicatePolicy::KeepLast {
+                    self.entries.remove(index);
+                    self.entries.push(element);
+                }
+            }
+            None => self.entries.push(element),
+        }
+        self
+    }
+
+    /// Adds all elements of the given iterator according to the configured [DuplicatePolicy].
+    pub fn extend(self, iter: impl IntoIterator<Item = T>) -> Self {
+        iter.into_iter().fold(self, Self::push)
+    }
+
+    /// Number of duplicates which were discarded so far.
+    pub fn duplicates(&self) -> usize {
+        self.duplicates
+    }
+
+    /// Consumes the builder and returns the final [UniqueVec].
+    pub fn build(self) -> UniqueVec<T> {
+        UniqueVec(self.entries)
+    }
+}
+
+impl<T> Default for UniqueVecBuilder<T>
+where
+    T: PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A borrowed view into a [UniqueVec] which only contains unique entries.
+///
+/// It is obtained by indexing a [UniqueVec] with a range or by [UniqueVec::get_range].
+/// Similarly to [UniqueVec], it dereferences to a regular [slice].
+///
+/// ```
+/// # use uniquevec::*;
+/// let unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+/// let view: &UniqueSlice<_> = &unique_vec[1..];
+/// assert_eq!(view, &[2, 3, 4][..]);
+///
+/// let (left, right) = view.split_at(1);
+/// assert_eq!(left, &[2][..]);
+/// assert_eq!(right, &[3, 4][..]);
+/// assert_eq!(right.to_unique_vec(), vec![3, 4]);
+///
+/// assert!(unique_vec[1..] == [2, 3, 4]);
+/// assert_eq!(&unique_vec[..2], &[1, 2]);
+/// assert_eq!(vec![3, 4], unique_vec[2..]);
+///
+/// use core::ops::Bound;
+/// assert_eq!(unique_vec[(Bound::Excluded(1), Bound::Unbounded)], [3, 4]);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct UniqueSlice<T>([T]);
+
+impl<T> UniqueSlice<T> {
+    fn from_slice(slice: &[T]) -> &Self {
+        // SAFETY: UniqueSlice is a transparent wrapper around a slice. All sub-slices of a
+        // UniqueVec only contain unique entries.
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// Divides the [UniqueSlice] into two at the given index.
+    /// See [slice::split_at].
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        let (left, right) = self.0.split_at(mid);
+        (Self::from_slice(left), Self::from_slice(right))
+    }
+
+    /// Clones all entries into a new [UniqueVec] without checking for duplicates again.
+    pub fn to_unique_vec(&self) -> UniqueVec<T>
+    where
+        T: Clone,
+    {
+        UniqueVec(self.0.to_vec())
+    }
+}
+
+impl<T> core::ops::Deref for UniqueSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for UniqueSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl_partial_eq! { [] UniqueSlice<T>, Vec<U> }
+impl_partial_eq! { [] UniqueSlice<T>, [U] }
+impl_partial_eq! { [] UniqueSlice<T>, &[U] }
+impl_partial_eq! { [const N: usize] UniqueSlice<T>, [U; N] }
+impl_partial_eq! { [const N: usize] UniqueSlice<T>, &[U; N] }
+impl_partial_eq! { [] Vec<T>, UniqueSlice<U> }
+impl_partial_eq! { [] [T], UniqueSlice<U> }
+impl_partial_eq! { [] &[T], UniqueSlice<U> }
+impl_partial_eq! { [const N: usize] [T; N], UniqueSlice<U> }
+impl_partial_eq! { [const N: usize] &[T; N], UniqueSlice<U> }
+
+/// ```
+/// # use uniquevec::UniqueVec;
+/// fn total<'a>(values: impl IntoIterator<Item = &'a u32>) -> u32 {
+///     values.into_iter().sum()
+/// }
+/// let unique_vec = UniqueVec::from_iter([1, 2, 3]).0;
+/// assert_eq!(total(&unique_vec[..2]), 3);
+/// for entry in &unique_vec[1..] {
+///     assert!(*entry > 1);
+/// }
+/// ```
+impl<'a, T> IntoIterator for &'a UniqueSlice<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+macro_rules! impl_index_range {
+    ($($range:ty),*) => {
+        $(
+            impl<T> core::ops::Index<$range> for UniqueVec<T> {
+                type Output = UniqueSlice<T>;
+
+                fn index(&self, index: $range) -> &Self::Output {
+                    UniqueSlice::from_slice(&self.0[index])
+                }
+            }
+
+            impl<T> core::ops::Index<$range> for UniqueSlice<T> {
+                type Output = UniqueSlice<T>;
+
+                fn index(&self, index: $range) -> &Self::Output {
+                    UniqueSlice::from_slice(&self.0[index])
+                }
+            }
+        )*
+    };
+}
+
+impl<T> core::ops::Index<usize> for UniqueVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T> core::ops::Index<usize> for UniqueSlice<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl_index_range!(
+    core::ops::Range<usize>,
+    core::ops::RangeFrom<usize>,
+    core::ops::RangeFull,
+    core::ops::RangeInclusive<usize>,
+    core::ops::RangeTo<usize>,
+    core::ops::RangeToInclusive<usize>,
+    (core::ops::Bound<usize>, core::ops::Bound<usize>)
+);
+
+/// Adapter which formats all entries of a [UniqueVec] joined with a separator.
+///
+/// It is created by [UniqueVec::display].
+#[derive(Clone, Copy, Debug)]
+pub struct Joined<'a, T> {
+    entries: &'a [T],
+    separator: &'a str,
+}
+
+impl<T> core::fmt::Display for Joined<'_, T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (n, entry) in self.entries.iter().enumerate() {
+            if n > 0 {
+                f.write_str(self.separator)?;
+            }
+            entry.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses all trimmed entries of a string which are separated by the given delimiter.
+fn parse_entries<'a, T>(
+    s: &'a str,
+    delimiter: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError<T, T::Err>>> + 'a
+where
+    T: core::str::FromStr,
+{
+    let s = s.trim();
+    s.split(delimiter)
+        .filter(move |_| !s.is_empty())
+        .enumerate()
+        .map(|(index, entry)| {
+            entry
+                .trim()
+                .parse()
+                .map_err(|error| ParseError::Invalid { index, error })
+        })
+}
+
+/// Owning iterator over the entries of a [UniqueVec].
+///
+/// It is created by the [IntoIterator] implementation of [UniqueVec].
+///
+/// ```
+/// # use uniquevec::UniqueVec;
+/// let unique_vec = UniqueVec::from_iter([1, 2, 3, 4]).0;
+/// let mut iter = unique_vec.into_iter();
+/// assert_eq!(iter.len(), 4);
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.next_back(), Some(4));
+/// assert_eq!(iter.as_slice(), &[2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntoIter<T>(alloc::vec::IntoIter<T>);
+
+impl<T> IntoIter<T> {
+    /// Returns all remaining entries as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T> core::iter::FusedIterator for IntoIter<T> {}
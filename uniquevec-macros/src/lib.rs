@@ -0,0 +1,117 @@
+//! Procedural macros for the [uniquevec](https://docs.rs/uniquevec) crate.
+//!
+//! This crate should not be used directly.
+//! Instead, activate the `macros` feature of the `uniquevec` crate which re-exports all macros.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Expr, Lit, Token, UnOp};
+
+/// Normalized value of a literal which is used to detect duplicates.
+///
+/// Byte literals are stored as integers since both can occur in the same `u8` array.
+#[derive(PartialEq)]
+enum LitValue {
+    Str(String),
+    ByteStr(Vec<u8>),
+    Char(char),
+    Int(bool, u128),
+    Float(f64),
+    Bool(bool),
+}
+
+impl LitValue {
+    /// Floats are compared at `f32` precision unless any float literal has the `f64` suffix.
+    /// Unsuffixed literals may be inferred to be `f32`, in which case values which differ as
+    /// `f64` can still be equal at runtime.
+    fn from_expr(expr: &Expr, f32_precision: bool) -> syn::Result<Self> {
+        match expr {
+            Expr::Lit(lit) => Self::from_lit(&lit.lit, false, f32_precision),
+            Expr::Group(group) => Self::from_expr(&group.expr, f32_precision),
+            Expr::Paren(paren) => Self::from_expr(&paren.expr, f32_precision),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match &*unary.expr {
+                Expr::Lit(lit) => Self::from_lit(&lit.lit, true, f32_precision),
+                _ => Err(syn::Error::new_spanned(expr, "expected a literal")),
+            },
+            _ => Err(syn::Error::new_spanned(expr, "expected a literal")),
+        }
+    }
+
+    fn from_lit(lit: &Lit, negative: bool, f32_precision: bool) -> syn::Result<Self> {
+        let value = match lit {
+            Lit::Int(int) => {
+                let value = int.base10_parse::<u128>()?;
+                Self::Int(negative && value != 0, value)
+            }
+            Lit::Float(float) => {
+                let value = if f32_precision {
+                    float.base10_parse::<f32>()? as f64
+                } else {
+                    float.base10_parse::<f64>()?
+                };
+                Self::Float(if negative { -value } else { value })
+            }
+            Lit::Str(s) if !negative => Self::Str(s.value()),
+            Lit::ByteStr(s) if !negative => Self::ByteStr(s.value()),
+            Lit::Byte(b) if !negative => Self::Int(false, b.value() as u128),
+            Lit::Char(c) if !negative => Self::Char(c.value()),
+            Lit::Bool(b) if !negative => Self::Bool(b.value),
+            _ => return Err(syn::Error::new_spanned(lit, "unsupported literal")),
+        };
+        Ok(value)
+    }
+}
+
+/// Returns the suffix of a (possibly negated) float literal.
+fn float_suffix(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Float(float) => Some(float.suffix()),
+            _ => None,
+        },
+        Expr::Group(group) => float_suffix(&group.expr),
+        Expr::Paren(paren) => float_suffix(&paren.expr),
+        Expr::Unary(unary) => float_suffix(&unary.expr),
+        _ => None,
+    }
+}
+
+/// Creates a `UniqueVec` from a list of literals and rejects duplicates at compile time.
+///
+/// See the documentation of the `uniquevec` crate for examples.
+#[proc_macro]
+pub fn unique_vec_strict(input: TokenStream) -> TokenStream {
+    let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+    let elements = match syn::parse::Parser::parse(parser, input) {
+        Ok(elements) => elements,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let f32_precision = !elements.iter().any(|e| float_suffix(e) == Some("f64"));
+    let explicit_f32 = elements.iter().any(|e| float_suffix(e) == Some("f32"));
+    let mut values: Vec<LitValue> = Vec::with_capacity(elements.len());
+    for element in elements.iter() {
+        let value = match LitValue::from_expr(element, f32_precision) {
+            Ok(value) => value,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        if values.contains(&value) {
+            let message = if f32_precision && !explicit_f32 && matches!(value, LitValue::Float(_)) {
+                "duplicate entry (float literals are compared at `f32` precision, \
+                add the `f64` suffix to any of them to compare at `f64` precision)"
+            } else {
+                "duplicate entry"
+            };
+            return syn::Error::new_spanned(element, message)
+                .to_compile_error()
+                .into();
+        }
+        values.push(value);
+    }
+
+    let elements = elements.into_iter();
+    quote!(::uniquevec::UniqueVec::from([#(#elements),*])).into()
+}